@@ -30,6 +30,7 @@ bench_binop!(_bench_quat_add_q, Quaternion<f32>, Quaternion<f32>, add_q);
 bench_binop!(_bench_quat_sub_q, Quaternion<f32>, Quaternion<f32>, sub_q);
 bench_binop!(_bench_quat_mul_q, Quaternion<f32>, Quaternion<f32>, mul_q);
 bench_binop!(_bench_quat_mul_v, Quaternion<f32>, Vector3<f32>, mul_v);
+bench_binop!(_bench_quat_rotate_vector_fast, Quaternion<f32>, Vector3<f32>, rotate_vector_fast);
 bench_binop_deref!(_bench_quat_mul_s, Quaternion<f32>, f32, mul_s);
 bench_binop_deref!(_bench_quat_div_s, Quaternion<f32>, f32, div_s);
 bench_unop!(_bench_quat_invert, Quaternion<f32>, invert);
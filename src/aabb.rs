@@ -23,7 +23,7 @@
 use bound::*;
 use point::{Point, Point2, Point3};
 use vector::{Vector, Vector2, Vector3};
-use ray::{Ray2};
+use ray::{Ray2, Ray3};
 use intersect::Intersect;
 use num::{zero, one, BaseNum, BaseFloat};
 use plane::Plane;
@@ -56,7 +56,8 @@ pub trait Aabb<S: BaseNum, V: Vector<S>, P: Point<S, V>>: Sized {
     }
 
     /// Tests whether a point is cointained in the box, inclusive for min corner
-    /// and exclusive for the max corner.
+    /// and exclusive for the max corner. As a consequence, a box with zero
+    /// extent along any axis contains no points.
     #[inline]
     fn contains(&self, p: &P) -> bool;
 
@@ -239,6 +240,50 @@ impl<S: BaseFloat> Intersect<Option<Point2<S>>> for (Ray2<S>, Aabb2<S>) {
     }
 }
 
+impl<S: BaseFloat> Aabb3<S> {
+    /// Find the parametric distance `t` along `ray` at which it first enters
+    /// the box, using the slab method. If the ray starts inside the box, `t`
+    /// is zero. Returns `None` if the ray misses the box.
+    pub fn intersects_ray(&self, ray: &Ray3<S>) -> Option<S> {
+        let mut tmin: S = Float::neg_infinity();
+        let mut tmax: S = Float::infinity();
+
+        if !clip_slab(self.min.x, self.max.x, ray.origin.x, ray.direction.x, &mut tmin, &mut tmax) ||
+           !clip_slab(self.min.y, self.max.y, ray.origin.y, ray.direction.y, &mut tmin, &mut tmax) ||
+           !clip_slab(self.min.z, self.max.z, ray.origin.z, ray.direction.z, &mut tmin, &mut tmax) {
+            return None;
+        }
+
+        if tmax < zero() || tmax < tmin {
+            None
+        } else {
+            Some(tmin.max(zero()))
+        }
+    }
+}
+
+/// Narrow `[tmin, tmax]` to the part of the ray lying between `min` and `max`
+/// along a single axis. A ray parallel to the slab is only kept if its origin
+/// lies within it, which avoids dividing by zero.
+fn clip_slab<S: BaseFloat>(min: S, max: S, origin: S, dir: S, tmin: &mut S, tmax: &mut S) -> bool {
+    if dir == zero() {
+        origin >= min && origin <= max
+    } else {
+        let t1 = (min - origin) / dir;
+        let t2 = (max - origin) / dir;
+        *tmin = tmin.max(t1.min(t2));
+        *tmax = tmax.min(t1.max(t2));
+        true
+    }
+}
+
+impl<S: BaseFloat> Intersect<Option<Point3<S>>> for (Ray3<S>, Aabb3<S>) {
+    fn intersection(&self) -> Option<Point3<S>> {
+        let (ref ray, ref aabb) = *self;
+        aabb.intersects_ray(ray).map(|t| ray.point_at(t))
+    }
+}
+
 impl<S: BaseFloat + 'static> Bound<S> for Aabb3<S> {
     fn relate_plane(&self, plane: &Plane<S>) -> Relation {
         let corners = self.to_corners();
@@ -28,9 +28,13 @@ use num::{BaseFloat, One, one, Zero, zero};
 
 /// An angle, in radians
 #[derive(Copy, Clone, PartialEq, PartialOrd, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
 pub struct Rad<S> { pub s: S }
 /// An angle, in degrees
 #[derive(Copy, Clone, PartialEq, PartialOrd, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
 pub struct Deg<S> { pub s: S }
 
 /// Create a new angle, in radians
@@ -136,7 +140,34 @@ pub trait Angle
     fn normalize_self(&mut self) {
         let full_turn: Self = Angle::full_turn();
         self.rem_self_s(full_turn.s().clone());
-        if *self < zero() { self.add_self_a(full_turn) };
+        if *self < zero() {
+            self.add_self_a(full_turn.clone());
+            // a tiny negative angle can round up to a full turn
+            if *self >= full_turn { *self = zero() }
+        };
+    }
+
+    /// Return the angle, normalized to the range `[-turn_div_2, turn_div_2)`.
+    #[inline]
+    fn normalize_signed(&self) -> Self {
+        let mut a = self.clone();
+        a.normalize_signed_self();
+        a
+    }
+
+    /// Normalize the angle to the range `[-turn_div_2, turn_div_2)`.
+    #[inline]
+    fn normalize_signed_self(&mut self) {
+        self.normalize_self();
+        if *self >= Angle::turn_div_2() { self.sub_self_a(Angle::full_turn()) };
+    }
+
+    /// Return the shortest signed rotation from `other` to this angle, in
+    /// the range `[-turn_div_2, turn_div_2)`. For example, the difference
+    /// between 10° and 350° is 20°, rather than -340°.
+    #[inline]
+    fn sub_wrapped(&self, other: Self) -> Self {
+        self.sub_a(other).normalize_signed()
     }
 
     /// Return the angle rotated by half a turn
@@ -151,6 +182,11 @@ pub trait Angle
         self.add_a(self.sub_a(other).mul_s(cast(0.5f64).unwrap())).normalize()
     }
 
+    /// Compute the sine and cosine of the angle together, which is cheaper
+    /// than calling `sin` and `cos` separately.
+    #[inline]
+    fn sin_cos(&self) -> (S, S) { self.to_rad().s.sin_cos() }
+
     fn full_turn() -> Self;
 
     #[inline] fn turn_div_2() -> Self { let full_turn: Self = Angle::full_turn(); full_turn.div_s(cast(2i8).unwrap()) }
@@ -165,21 +201,37 @@ pub trait Angle
 
 impl<S: BaseFloat>
 Rad<S> {
+    /// The zero angle, `0` radians.
     #[inline] pub fn zero() -> Rad<S> { zero() }
+    /// A full turn, `2π` radians.
     #[inline] pub fn full_turn() -> Rad<S> { Angle::full_turn() }
+    /// Half a turn, `π` radians.
+    #[inline] pub fn half_turn() -> Rad<S> { Angle::turn_div_2() }
+    /// Half a turn, `π` radians. The same as `half_turn`.
     #[inline] pub fn turn_div_2() -> Rad<S> { Angle::turn_div_2() }
+    /// A third of a turn, `2π/3` radians.
     #[inline] pub fn turn_div_3() -> Rad<S> { Angle::turn_div_3() }
+    /// A quarter turn, `π/2` radians.
     #[inline] pub fn turn_div_4() -> Rad<S> { Angle::turn_div_4() }
+    /// A sixth of a turn, `π/3` radians.
     #[inline] pub fn turn_div_6() -> Rad<S> { Angle::turn_div_6() }
 }
 
 impl<S: BaseFloat>
 Deg<S> {
+    /// The zero angle, `0` degrees.
     #[inline] pub fn zero() -> Deg<S> { zero() }
+    /// A full turn, `360` degrees.
     #[inline] pub fn full_turn() -> Deg<S> { Angle::full_turn() }
+    /// Half a turn, `180` degrees.
+    #[inline] pub fn half_turn() -> Deg<S> { Angle::turn_div_2() }
+    /// Half a turn, `180` degrees. The same as `half_turn`.
     #[inline] pub fn turn_div_2() -> Deg<S> { Angle::turn_div_2() }
+    /// A third of a turn, `120` degrees.
     #[inline] pub fn turn_div_3() -> Deg<S> { Angle::turn_div_3() }
+    /// A quarter turn, `90` degrees.
     #[inline] pub fn turn_div_4() -> Deg<S> { Angle::turn_div_4() }
+    /// A sixth of a turn, `60` degrees.
     #[inline] pub fn turn_div_6() -> Deg<S> { Angle::turn_div_6() }
 }
 
@@ -304,6 +356,16 @@ ApproxEq<S> for Rad<S> {
     fn approx_eq_eps(&self, other: &Rad<S>, epsilon: &S) -> bool {
         self.s.approx_eq_eps(&other.s, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Rad<S>, ulps: u32) -> bool {
+        self.s.approx_eq_ulps(&other.s, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Rad<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.s.relative_eq_eps(&other.s, epsilon, max_relative)
+    }
 }
 
 impl<S: BaseFloat>
@@ -312,6 +374,16 @@ ApproxEq<S> for Deg<S> {
     fn approx_eq_eps(&self, other: &Deg<S>, epsilon: &S) -> bool {
         self.s.approx_eq_eps(&other.s, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Deg<S>, ulps: u32) -> bool {
+        self.s.approx_eq_ulps(&other.s, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Deg<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.s.relative_eq_eps(&other.s, epsilon, max_relative)
+    }
 }
 
 impl<S: BaseFloat + PartialOrd + SampleRange + Rand> Rand for Rad<S> {
@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::mem;
 use std::num;
 use std::num::Float;
 
@@ -27,22 +28,74 @@ pub trait ApproxEq<T: Float>: Sized {
     }
 
     fn approx_eq_eps(&self, other: &Self, epsilon: &T) -> bool;
+
+    /// Compare two values by the number of representable floating point
+    /// values between them (units in the last place), component-wise. Values
+    /// are equal if they are at most `ulps` apart, so `ulps` of zero requires
+    /// them to be identical, except that `0.0` and `-0.0` are always equal.
+    ///
+    /// `NaN` is never equal to anything, and values of opposite sign are
+    /// never equal unless both are zero.
+    fn approx_eq_ulps(&self, other: &Self, ulps: u32) -> bool;
+
+    /// Compare two values with a tolerance relative to their magnitude,
+    /// component-wise, using the default epsilon as the absolute tolerance
+    /// near zero. See `relative_eq_eps` for details.
+    fn relative_eq(&self, other: &Self, max_relative: &T) -> bool {
+        let eps: T = ApproxEq::approx_epsilon(None::<Self>);
+        self.relative_eq_eps(other, &eps, max_relative)
+    }
+
+    /// Compare two values with a tolerance relative to their magnitude,
+    /// component-wise. Values are equal if they differ by at most `epsilon`,
+    /// which handles values close to zero, or by at most `max_relative` times
+    /// the larger of their absolute values.
+    ///
+    /// `NaN` is never equal to anything, and infinities are only equal to
+    /// themselves.
+    fn relative_eq_eps(&self, other: &Self, epsilon: &T, max_relative: &T) -> bool;
 }
 
 
 macro_rules! approx_float(
-    ($S:ident) => (
+    ($S:ident, $I:ident) => (
         impl ApproxEq<$S> for $S {
              #[inline]
             fn approx_eq_eps(&self, other: &$S, epsilon: &$S) -> bool {
                  (*self - *other).abs() < *epsilon
             }
+
+            fn approx_eq_ulps(&self, other: &$S, ulps: u32) -> bool {
+                if self.is_nan() || other.is_nan() { return false; }
+                if *self == *other { return true; }
+
+                // the bit patterns of floats with the same sign are ordered
+                // in the same way as the floats themselves
+                let a: $I = unsafe { mem::transmute(*self) };
+                let b: $I = unsafe { mem::transmute(*other) };
+                if (a < 0) != (b < 0) { return false; }
+                // with the same sign, the difference is non-negative and fits
+                // in a `u64`, as does any `ulps`, so neither cast can wrap
+                let diff = if a > b { a - b } else { b - a };
+                diff as u64 <= ulps as u64
+            }
+
+            fn relative_eq_eps(&self, other: &$S, epsilon: &$S, max_relative: &$S) -> bool {
+                if *self == *other { return true; }
+                if self.is_infinite() || other.is_infinite() { return false; }
+
+                let diff = (*self - *other).abs();
+                if diff <= *epsilon { return true; }
+
+                let largest = self.abs().max(other.abs());
+                diff <= largest * *max_relative
+            }
         }
     )
 );
 
-approx_float!(f32);
-approx_float!(f64);
+approx_float!(f32, i32);
+approx_float!(f64, i64);
 
 #[macro_export]
 macro_rules! assert_approx_eq_eps(
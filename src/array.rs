@@ -60,7 +60,8 @@ pub trait Array2<Column: Array1<Element>+'static, Row: Array1<Element>, Element:
         &mut (*self)[0][0]
     }
 
-    /// Swap two columns of this array.
+    /// Swap two columns of this array. Panics if either index is out of
+    /// range.
     #[inline]
     fn swap_cols(&mut self, a: usize, b: usize) {
         unsafe { ptr::swap(&mut (*self)[a], &mut (*self)[b]) };
@@ -72,10 +73,12 @@ pub trait Array2<Column: Array1<Element>+'static, Row: Array1<Element>, Element:
         mem::replace(&mut (*self)[c], src)
     }
 
-    /// Get a row from this array by-value.
+    /// Get a row from this array by-value. Since the array is stored in
+    /// column-major order, this gathers the `r`th element of each column.
+    /// Panics if `r` is out of range.
     fn row(&self, r: usize) -> Row;
 
-    /// Swap two rows of this array.
+    /// Swap two rows of this array. Panics if either index is out of range.
     fn swap_rows(&mut self, a: usize, b: usize);
 
     /// Swap the values at index `a` and `b`
@@ -0,0 +1,83 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpolating curves through control points
+
+use std::num::cast;
+
+use num::{BaseFloat, one};
+use point::Point;
+use vector::Vector;
+
+/// Evaluate the uniform Catmull-Rom spline segment between `p1` and `p2` at
+/// `t`, using `p0` and `p3` as the neighbouring control points. The curve
+/// passes through `p1` at `t = 0` and `p2` at `t = 1`, and consecutive
+/// segments along a path share their tangents at the joins.
+pub fn catmull_rom<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(p0: &P, p1: &P, p2: &P, p3: &P, t: S) -> P {
+    let half: S = cast(0.5f64).unwrap();
+    let two: S = cast(2i8).unwrap();
+    let three: S = cast(3i8).unwrap();
+    let four: S = cast(4i8).unwrap();
+
+    // Work relative to `p1`, so that the basis only combines vectors.
+    let a = p0.sub_p(p1);
+    let c = p2.sub_p(p1);
+    let d = p3.sub_p(p1);
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let v = c.sub_v(&a).mul_s(t)
+             .add_v(&a.mul_s(two).add_v(&c.mul_s(four)).sub_v(&d).mul_s(t2))
+             .add_v(&d.sub_v(&a).sub_v(&c.mul_s(three)).mul_s(t3));
+    p1.add_v(&v.mul_s(half))
+}
+
+/// Interpolate between two points so that `t = 0` and `t = 1` give exactly
+/// `a` and `b` respectively.
+#[inline]
+fn lerp_exact<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(a: &P, b: &P, t: S) -> P {
+    let s = one::<S>() - t;
+    Point::from_vec(&a.to_vec().mul_s(s).add_v(&b.to_vec().mul_s(t)))
+}
+
+/// Evaluate the quadratic Bezier curve with control points `p0`, `p1` and
+/// `p2` at `t`, using de Casteljau's algorithm.
+pub fn bezier2<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(p0: &P, p1: &P, p2: &P, t: S) -> P {
+    let a = lerp_exact(p0, p1, t);
+    let b = lerp_exact(p1, p2, t);
+    lerp_exact(&a, &b, t)
+}
+
+/// Evaluate the cubic Bezier curve with control points `p0` through `p3` at
+/// `t`, using de Casteljau's algorithm. The curve starts at `p0` and ends at
+/// `p3`.
+pub fn bezier3<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(p0: &P, p1: &P, p2: &P, p3: &P, t: S) -> P {
+    let a = lerp_exact(p0, p1, t);
+    let b = lerp_exact(p1, p2, t);
+    let c = lerp_exact(p2, p3, t);
+    bezier2(&a, &b, &c, t)
+}
+
+/// The derivative of the cubic Bezier curve `bezier3(p0, p1, p2, p3, t)` with
+/// respect to `t`. This is not normalized; its length is the speed at which
+/// the curve is traversed.
+pub fn bezier3_tangent<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(p0: &P, p1: &P, p2: &P, p3: &P, t: S) -> V {
+    let three: S = cast(3i8).unwrap();
+    let a = lerp_exact(p0, p1, t);
+    let b = lerp_exact(p1, p2, t);
+    let c = lerp_exact(p2, p3, t);
+    lerp_exact(&b, &c, t).sub_p(&lerp_exact(&a, &b, t)).mul_s(three)
+}
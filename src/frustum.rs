@@ -15,12 +15,14 @@
 
 //! View frustum for visibility determination
 
+use approx::ApproxEq;
 use array::Array2;
 use bound::*;
 use matrix::Matrix4;
-use num::BaseFloat;
+use num::{BaseFloat, zero};
 use plane::Plane;
 use point::Point3;
+use sphere::Sphere;
 use vector::{Vector, EuclideanVector};
 
 #[derive(Copy, Clone, Debug, PartialEq, RustcEncodable, RustcDecodable)]
@@ -79,6 +81,22 @@ Frustum<S> {
             max(cur, r)
         })
     }
+
+    /// Tests whether a point lies inside this frustum. Points that lie on one
+    /// of the planes, to within the default epsilon, are considered inside.
+    pub fn contains_point(&self, p: &Point3<S>) -> bool {
+        [&self.left, &self.right, &self.top, &self.bottom, &self.near, &self.far]
+            .iter().all(|plane| {
+            let dist = plane.distance_to_point(p);
+            dist > zero() || dist.approx_eq(&zero())
+        })
+    }
+
+    /// Find the spatial relation of a sphere inside this frustum.
+    #[inline]
+    pub fn contains_sphere(&self, sphere: &Sphere<S>) -> Relation {
+        self.contains(sphere)
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
@@ -16,6 +16,7 @@
 #![crate_type = "rlib"]
 #![crate_type = "dylib"]
 #![feature(old_impl_check, plugin, core, std_misc, custom_derive)]
+#![cfg_attr(feature = "serde_serialization", plugin(serde_macros))]
 
 //! Computer graphics-centric math.
 //!
@@ -33,6 +34,8 @@
 
 extern crate "rustc-serialize" as rustc_serialize;
 extern crate rand;
+#[cfg(feature = "serde_serialization")]
+extern crate serde;
 
 // Re-exports
 
@@ -44,6 +47,7 @@ pub use vector::*;
 pub use angle::*;
 pub use plane::Plane;
 pub use point::*;
+pub use curve::*;
 pub use line::*;
 pub use ray::*;
 pub use rotation::*;
@@ -58,6 +62,7 @@ pub use frustum::{Frustum, FrustumPoints};
 pub use intersect::Intersect;
 pub use obb::*;
 pub use sphere::Sphere;
+pub use triangle::*;
 
 pub use approx::ApproxEq;
 pub use num::*;
@@ -73,6 +78,7 @@ mod vector;
 mod angle;
 mod plane;
 mod point;
+mod curve;
 mod line;
 mod ray;
 mod rotation;
@@ -87,6 +93,7 @@ mod frustum;
 mod intersect;
 mod obb;
 mod sphere;
+mod triangle;
 
 mod approx;
 mod num;
@@ -16,8 +16,9 @@
 //! Line segments
 
 use num::{BaseNum, BaseFloat, Zero, zero, One, one};
+use approx::ApproxEq;
 use point::{Point, Point2, Point3};
-use vector::{Vector, Vector2};
+use vector::{Vector, Vector2, EuclideanVector};
 use ray::{Ray2};
 use intersect::Intersect;
 
@@ -35,6 +36,80 @@ impl<S: BaseNum, V: Vector<S>, P: Point<S, V>>  Line<P> {
     }
 }
 
+#[old_impl_check]
+impl<S: BaseFloat, V: EuclideanVector<S>, P: Point<S, V>> Line<P> {
+    /// The point at parameter `t` along the segment, where `t = 0` is the
+    /// origin and `t = 1` is the destination.
+    #[inline]
+    fn at(&self, t: S) -> P {
+        self.origin.add_v(&self.dest.sub_p(&self.origin).mul_s(t))
+    }
+
+    /// The point on the segment that is closest to `p`. The projection of `p`
+    /// onto the line is clamped to the segment, so this is one of the end
+    /// points when `p` lies beyond either end.
+    pub fn closest_point(&self, p: &P) -> P {
+        let d = self.dest.sub_p(&self.origin);
+        let len2 = d.length2();
+        if len2.approx_eq(&zero()) {
+            return self.origin.clone();
+        }
+        self.at(clamp01(p.sub_p(&self.origin).dot(&d) / len2))
+    }
+
+    /// The distance from `p` to the closest point on the segment.
+    #[inline]
+    pub fn distance_to_point(&self, p: &P) -> S {
+        self.closest_point(p).sub_p(p).length()
+    }
+
+    /// The closest pair of points between this segment and `other`, with the
+    /// first point on `self` and the second on `other`. If the segments are
+    /// parallel and overlap, one of the many closest pairs is returned.
+    pub fn closest_points_between(&self, other: &Line<P>) -> (P, P) {
+        // From Ericson, "Real-Time Collision Detection", section 5.1.9
+        let d1 = self.dest.sub_p(&self.origin);
+        let d2 = other.dest.sub_p(&other.origin);
+        let r = self.origin.sub_p(&other.origin);
+        let a = d1.length2();
+        let e = d2.length2();
+        let f = d2.dot(&r);
+
+        let (s, t) = if a.approx_eq(&zero()) && e.approx_eq(&zero()) {
+            // Both segments are points
+            (zero(), zero())
+        } else if a.approx_eq(&zero()) {
+            (zero(), clamp01(f / e))
+        } else {
+            let c = d1.dot(&r);
+            if e.approx_eq(&zero()) {
+                (clamp01(-c / a), zero())
+            } else {
+                let b = d1.dot(&d2);
+                let denom = a * e - b * b;
+                // Pick an arbitrary point on `self` if the segments are parallel
+                let s = if denom.approx_eq(&zero()) { zero() }
+                        else { clamp01((b * f - c * e) / denom) };
+                let t = (b * s + f) / e;
+                if t < zero() {
+                    (clamp01(-c / a), zero())
+                } else if t > one() {
+                    (clamp01((b - c) / a), one())
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        (self.at(s), other.at(t))
+    }
+}
+
+#[inline]
+fn clamp01<S: BaseFloat>(t: S) -> S {
+    t.max(zero()).min(one())
+}
+
 pub type Line2<S> = Line<Point2<S>>;
 pub type Line3<S> = Line<Point3<S>>;
 
@@ -15,9 +15,12 @@
 
 //! Column major, square matrix types and traits.
 
+use std::default::Default;
 use std::fmt;
 use std::mem;
-use std::num::cast;
+use std::ptr;
+use std::slice;
+use std::num::{cast, Float, NumCast};
 use std::ops::*;
 
 use rand::{Rand, Rng};
@@ -33,14 +36,23 @@ use vector::{Vector2, Vector3, Vector4};
 
 /// A 2 x 2, column major matrix
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
 pub struct Matrix2<S> { pub x: Vector2<S>, pub y: Vector2<S> }
 
 /// A 3 x 3, column major matrix
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
 pub struct Matrix3<S> { pub x: Vector3<S>, pub y: Vector3<S>, pub z: Vector3<S> }
 
 /// A 4 x 4, column major matrix
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
 pub struct Matrix4<S> { pub x: Vector4<S>, pub y: Vector4<S>, pub z: Vector4<S>, pub w: Vector4<S> }
 
 
@@ -73,11 +85,47 @@ impl<S: BaseNum> Matrix2<S> {
         Matrix2::from_value(zero())
     }
 
+    /// Create a diagonal matrix from a vector, for example to represent a
+    /// non-uniform scale.
+    #[inline]
+    pub fn from_diagonal(value: &Vector2<S>) -> Matrix2<S> {
+        Matrix2::new(value.x, zero(),
+                     zero(),  value.y)
+    }
+
     /// Create an identity matrix (diagonal matrix of ones).
     #[inline]
     pub fn identity() -> Matrix2<S> {
         Matrix2::from_value(one())
     }
+
+    /// View the elements of the matrix as a slice of length 4, in
+    /// column-major order. The matrix types are `#[repr(C)]`, so there is no
+    /// padding between the columns.
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [S] {
+        unsafe { slice::from_raw_parts(&self.x.x, 4) }
+    }
+
+    /// View the elements of the matrix as a mutable slice, in column-major
+    /// order.
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [S] {
+        unsafe { slice::from_raw_parts_mut(&mut self.x.x, 4) }
+    }
+
+    /// Iterate over the elements of the matrix, in column-major order.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> slice::Iter<'a, S> {
+        self.as_slice().iter()
+    }
+
+    /// Iterate mutably over the elements of the matrix, in column-major
+    /// order.
+    #[inline]
+    pub fn iter_mut<'a>(&'a mut self) -> slice::IterMut<'a, S> {
+        self.as_mut_slice().iter_mut()
+    }
 }
 
 impl<S: BaseFloat + 'static> Matrix2<S> {
@@ -130,21 +178,86 @@ impl<S: BaseNum> Matrix3<S> {
         Matrix3::from_value(zero())
     }
 
+    /// Create a diagonal matrix from a vector, for example to represent a
+    /// non-uniform scale.
+    #[inline]
+    pub fn from_diagonal(value: &Vector3<S>) -> Matrix3<S> {
+        Matrix3::new(value.x, zero(),  zero(),
+                     zero(),  value.y, zero(),
+                     zero(),  zero(),  value.z)
+    }
+
     /// Create an identity matrix (diagonal matrix of ones).
     #[inline]
     pub fn identity() -> Matrix3<S> {
         Matrix3::from_value(one())
     }
+
+    /// Create a homogeneous translation matrix for two-dimensional points
+    /// from a Vector2.
+    #[inline]
+    pub fn from_translation(v: &Vector2<S>) -> Matrix3<S> {
+        Matrix3::new(one(),  zero(), zero(),
+                     zero(), one(),  zero(),
+                     v.x,    v.y,    one())
+    }
+
+    /// Create a homogeneous shear matrix for two-dimensional points. A point
+    /// `(x, y)` is mapped to `(x + shx * y, y + shy * x)`.
+    #[inline]
+    pub fn from_shear_2d(shx: S, shy: S) -> Matrix3<S> {
+        Matrix3::new(one(),  shy,    zero(),
+                     shx,    one(),  zero(),
+                     zero(), zero(), one())
+    }
+
+    /// View the elements of the matrix as a slice of length 9, in
+    /// column-major order. The matrix types are `#[repr(C)]`, so there is no
+    /// padding between the columns.
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [S] {
+        unsafe { slice::from_raw_parts(&self.x.x, 9) }
+    }
+
+    /// View the elements of the matrix as a mutable slice, in column-major
+    /// order.
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [S] {
+        unsafe { slice::from_raw_parts_mut(&mut self.x.x, 9) }
+    }
+
+    /// Iterate over the elements of the matrix, in column-major order.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> slice::Iter<'a, S> {
+        self.as_slice().iter()
+    }
+
+    /// Iterate mutably over the elements of the matrix, in column-major
+    /// order.
+    #[inline]
+    pub fn iter_mut<'a>(&'a mut self) -> slice::IterMut<'a, S> {
+        self.as_mut_slice().iter_mut()
+    }
 }
 
 impl<S: BaseFloat + 'static>
 Matrix3<S> {
-    /// Create a transformation matrix that will cause a vector to point at
-    /// `dir`, using `up` for orientation.
+    /// Create a rotation matrix that maps `dir` onto the positive `z` axis,
+    /// using `up` for orientation. The rows of the result are the side, up
+    /// and (normalized) `dir` vectors.
+    ///
+    /// If `up` is parallel to `dir`, a fallback up vector is chosen from the
+    /// `x` or `y` axis so that the result is still a valid rotation.
     pub fn look_at(dir: &Vector3<S>, up: &Vector3<S>) -> Matrix3<S> {
         let dir = dir.normalize();
-        let side = up.cross(&dir).normalize();
-        let up = dir.cross(&side).normalize();
+        let mut side = up.cross(&dir);
+        if side.length2().approx_eq(&zero()) {
+            let alt = if dir.x.abs() < cast(0.9f64).unwrap() { Vector3::unit_x() }
+                      else { Vector3::unit_y() };
+            side = alt.cross(&dir);
+        }
+        let side = side.normalize();
+        let up = dir.cross(&side);
 
         Matrix3::from_cols(side, up, dir).transpose()
     }
@@ -176,7 +289,10 @@ Matrix3<S> {
                          zero(),    zero(),  one())
     }
 
-    /// Create a matrix from a set of euler angles.
+    /// Create a matrix from a set of euler angles. The rotations are applied
+    /// intrinsically in the order `z`, `y`, `x`, so the result is
+    /// `from_angle_z(z) * from_angle_y(y) * from_angle_x(x)`. This matches
+    /// `Quaternion::from_euler`.
     ///
     /// # Parameters
     ///
@@ -194,7 +310,8 @@ Matrix3<S> {
                       sx * sz + cx * sy * cz, -sx * cz + cx * sy * sz, cx * cy)
     }
 
-    /// Create a matrix from a rotation around an arbitrary axis
+    /// Create a matrix from a rotation of `angle` around an arbitrary `axis`,
+    /// using Rodrigues' rotation formula. The axis must be of unit length.
     pub fn from_axis_angle(axis: &Vector3<S>, angle: Rad<S>) -> Matrix3<S> {
         let (s, c) = sin_cos(angle);
         let _1subc = one::<S>() - c;
@@ -212,11 +329,42 @@ Matrix3<S> {
                      _1subc * axis.z * axis.z + c)
     }
 
-    /// Create a matrix from a non-uniform scale
-    pub fn from_diagonal(value: &Vector3<S>) -> Matrix3<S> {
-        Matrix3::new(value.x, zero(),  zero(),
-                     zero(),  value.y, zero(),
-                     zero(),  zero(),  value.z)
+    /// Re-orthonormalize the columns of the matrix using the Gram-Schmidt
+    /// process. This can be used to correct the drift that accumulates in a
+    /// rotation matrix after many multiplications.
+    ///
+    /// The direction of the first column is preserved, so it must not be
+    /// zero. If the second or third column is nearly parallel to the columns
+    /// before it, a perpendicular vector is substituted for it instead.
+    pub fn orthonormalize(&self) -> Matrix3<S> {
+        let x = self.x.normalize();
+
+        let mut y = self.y.sub_v(&x.mul_s(x.dot(&self.y)));
+        if y.length2().approx_eq(&zero()) {
+            y = self.z.cross(&x);
+            if y.length2().approx_eq(&zero()) {
+                let axis = if x.x.abs() < cast(0.9f64).unwrap() { Vector3::unit_x() }
+                           else { Vector3::unit_y() };
+                y = axis.cross(&x);
+            }
+        }
+        let y = y.normalize();
+
+        let mut z = self.z.sub_v(&x.mul_s(x.dot(&self.z)))
+                          .sub_v(&y.mul_s(y.dot(&self.z)));
+        if z.length2().approx_eq(&zero()) {
+            z = x.cross(&y);
+        }
+        let z = z.normalize();
+
+        Matrix3::from_cols(x, y, z)
+    }
+
+    /// Re-orthonormalize the columns of the matrix in-place. See
+    /// `orthonormalize` for details.
+    #[inline]
+    pub fn orthonormalize_self(&mut self) {
+        *self = self.orthonormalize();
     }
 }
 
@@ -255,12 +403,50 @@ impl<S: BaseNum> Matrix4<S> {
         Matrix4::from_value(zero())
     }
 
+    /// Create a diagonal matrix from a vector, for example to represent a
+    /// non-uniform scale in homogeneous coordinates.
+    #[inline]
+    pub fn from_diagonal(value: &Vector4<S>) -> Matrix4<S> {
+        Matrix4::new(value.x, zero(),  zero(),  zero(),
+                     zero(),  value.y, zero(),  zero(),
+                     zero(),  zero(),  value.z, zero(),
+                     zero(),  zero(),  zero(),  value.w)
+    }
+
     /// Create an identity matrix (diagonal matrix of ones).
     #[inline]
     pub fn identity() -> Matrix4<S> {
         Matrix4::from_value(one())
     }
 
+    /// View the elements of the matrix as a slice of length 16, in
+    /// column-major order. The matrix types are `#[repr(C)]`, so there is no
+    /// padding between the columns.
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [S] {
+        unsafe { slice::from_raw_parts(&self.x.x, 16) }
+    }
+
+    /// View the elements of the matrix as a mutable slice, in column-major
+    /// order.
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [S] {
+        unsafe { slice::from_raw_parts_mut(&mut self.x.x, 16) }
+    }
+
+    /// Iterate over the elements of the matrix, in column-major order.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> slice::Iter<'a, S> {
+        self.as_slice().iter()
+    }
+
+    /// Iterate mutably over the elements of the matrix, in column-major
+    /// order.
+    #[inline]
+    pub fn iter_mut<'a>(&'a mut self) -> slice::IterMut<'a, S> {
+        self.as_mut_slice().iter_mut()
+    }
+
     /// Create a translation matrix from a Vector3
     #[inline]
     pub fn from_translation(v: &Vector3<S>) -> Matrix4<S> {
@@ -269,12 +455,39 @@ impl<S: BaseNum> Matrix4<S> {
                      zero(), zero(), one(),  zero(),
                      v.x,    v.y,    v.z,    one())
     }
+
+    /// Create a homogeneous transformation matrix that scales uniformly by
+    /// `value`.
+    #[inline]
+    pub fn from_scale(value: S) -> Matrix4<S> {
+        Matrix4::from_nonuniform_scale(&Vector3::new(value, value, value))
+    }
+
+    /// Create a homogeneous transformation matrix that scales by `v.x`, `v.y`
+    /// and `v.z` along the respective axes.
+    #[inline]
+    pub fn from_nonuniform_scale(v: &Vector3<S>) -> Matrix4<S> {
+        Matrix4::from_diagonal(&v.extend(one()))
+    }
+
+    /// Create a homogeneous shear matrix. Each factor gives the amount by
+    /// which the first named coordinate is displaced per unit of the second,
+    /// so that a point `(x, y, z)` is mapped to
+    /// `(x + xy * y + xz * z, y + yx * x + yz * z, z + zx * x + zy * y)`.
+    #[inline]
+    pub fn from_shear(xy: S, xz: S, yx: S, yz: S, zx: S, zy: S) -> Matrix4<S> {
+        Matrix4::new(one(),  yx,     zx,     zero(),
+                     xy,     one(),  zy,     zero(),
+                     xz,     yz,     one(),  zero(),
+                     zero(), zero(), zero(), one())
+    }
 }
 
 impl<S: BaseFloat>
 Matrix4<S> {
-    /// Create a transformation matrix that will cause a vector to point at
-    /// `dir`, using `up` for orientation.
+    /// Create a right-handed view matrix for a camera at `eye` looking at
+    /// `center`, using `up` for orientation. In view space the camera looks
+    /// down the negative `z` axis, as is conventional in OpenGL.
     pub fn look_at(eye: &Point3<S>, center: &Point3<S>, up: &Vector3<S>) -> Matrix4<S> {
         let f = center.sub_p(eye).normalize();
         let s = f.cross(up).normalize();
@@ -285,6 +498,117 @@ Matrix4<S> {
                       s.z.clone(),  u.z.clone(), -f.z.clone(), zero(),
                      -eye.dot(&s), -eye.dot(&u),  eye.dot(&f),  one())
     }
+
+    /// Create a right-handed view matrix. This is the same as `look_at`, and
+    /// is provided to make the handedness explicit alongside `look_at_lh`.
+    #[inline]
+    pub fn look_at_rh(eye: &Point3<S>, center: &Point3<S>, up: &Vector3<S>) -> Matrix4<S> {
+        Matrix4::look_at(eye, center, up)
+    }
+
+    /// Create a left-handed view matrix for a camera at `eye` looking at
+    /// `center`, using `up` for orientation. In view space the camera looks
+    /// down the positive `z` axis, as is conventional in Direct3D.
+    pub fn look_at_lh(eye: &Point3<S>, center: &Point3<S>, up: &Vector3<S>) -> Matrix4<S> {
+        let f = center.sub_p(eye).normalize();
+        let s = up.cross(&f).normalize();
+        let u = f.cross(&s);
+
+        Matrix4::new( s.x.clone(),  u.x.clone(),  f.x.clone(), zero(),
+                      s.y.clone(),  u.y.clone(),  f.y.clone(), zero(),
+                      s.z.clone(),  u.z.clone(),  f.z.clone(), zero(),
+                     -eye.dot(&s), -eye.dot(&u), -eye.dot(&f),  one())
+    }
+}
+
+impl<S: BaseFloat + 'static> Matrix4<S> {
+    /// Create a homogeneous transformation matrix from a rotation around the
+    /// `x` axis (pitch).
+    pub fn from_angle_x(theta: Rad<S>) -> Matrix4<S> {
+        Matrix3::from_angle_x(theta).to_matrix4()
+    }
+
+    /// Create a homogeneous transformation matrix from a rotation around the
+    /// `y` axis (yaw).
+    pub fn from_angle_y(theta: Rad<S>) -> Matrix4<S> {
+        Matrix3::from_angle_y(theta).to_matrix4()
+    }
+
+    /// Create a homogeneous transformation matrix from a rotation around the
+    /// `z` axis (roll).
+    pub fn from_angle_z(theta: Rad<S>) -> Matrix4<S> {
+        Matrix3::from_angle_z(theta).to_matrix4()
+    }
+
+    /// Invert an affine transformation matrix. This is cheaper than the
+    /// general `invert`, because only the upper-left 3x3 block needs to be
+    /// inverted. If that block is orthonormal, as it is for a combination of a
+    /// rotation and a translation, use `invert_rigid` to avoid inverting it at
+    /// all.
+    ///
+    /// The bottom row is assumed to be `[0, 0, 0, 1]`. The result is
+    /// meaningless for matrices that do not satisfy this, such as projections.
+    /// Returns `None` if the upper-left block is not invertible.
+    pub fn invert_affine(&self) -> Option<Matrix4<S>> {
+        let m = Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate());
+        m.invert().map(|inv| {
+            let t = inv.mul_v(&self.w.truncate()).neg();
+            Matrix4::from_cols(inv.x.extend(zero()),
+                               inv.y.extend(zero()),
+                               inv.z.extend(zero()),
+                               t.extend(one()))
+        })
+    }
+
+    /// Invert a rigid transformation matrix, made up only of a rotation and a
+    /// translation, by transposing the rotation and applying it to the negated
+    /// translation.
+    ///
+    /// The upper-left 3x3 block is assumed to be orthonormal and the bottom row
+    /// to be `[0, 0, 0, 1]`. The result is meaningless for matrices that do not
+    /// satisfy this, for example ones that contain a scale.
+    pub fn invert_rigid(&self) -> Matrix4<S> {
+        let r = Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate()).transpose();
+        let t = r.mul_v(&self.w.truncate()).neg();
+        Matrix4::from_cols(r.x.extend(zero()),
+                           r.y.extend(zero()),
+                           r.z.extend(zero()),
+                           t.extend(one()))
+    }
+
+    /// Split an affine transformation matrix into a non-uniform scale, a
+    /// rotation and a translation, such that the matrix applies the scale
+    /// first, then the rotation, and then the translation.
+    ///
+    /// If the upper-left 3x3 block has a negative determinant, the transform
+    /// includes a reflection. This cannot be represented by a rotation, so
+    /// the `x` component of the returned scale is negated instead.
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 0, 1]`, if any axis is
+    /// scaled to zero, or if the matrix contains a shear.
+    pub fn decompose(&self) -> Option<(Vector3<S>, Quaternion<S>, Vector3<S>)> {
+        if !self.x.w.approx_eq(&zero()) || !self.y.w.approx_eq(&zero()) ||
+           !self.z.w.approx_eq(&zero()) || !self.w.w.approx_eq(&one()) {
+            return None;
+        }
+
+        let m = Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate());
+        let mut scale = Vector3::new(m.x.length(), m.y.length(), m.z.length());
+        if scale.x.approx_eq(&zero()) || scale.y.approx_eq(&zero()) || scale.z.approx_eq(&zero()) {
+            return None;
+        }
+        if m.determinant() < zero() {
+            scale.x = -scale.x;
+        }
+
+        let rot = Matrix3::from_cols(m.x.div_s(scale.x), m.y.div_s(scale.y), m.z.div_s(scale.z));
+        if !rot.x.dot(&rot.y).approx_eq(&zero()) || !rot.x.dot(&rot.z).approx_eq(&zero()) ||
+           !rot.y.dot(&rot.z).approx_eq(&zero()) {
+            return None;
+        }
+
+        Some((scale, rot.to_quaternion(), self.w.truncate()))
+    }
 }
 
 pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
@@ -336,6 +660,20 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
     #[inline]
     fn mul_self_m(&mut self, m: &Self) { *self = self.mul_m(m); }
 
+    /// Raise this matrix to the power `n` by repeated squaring, which takes
+    /// `O(log n)` matrix multiplications. Returns the identity matrix if `n`
+    /// is zero.
+    #[must_use]
+    fn pow(&self, n: u32) -> Self {
+        if n == 0 {
+            one()
+        } else {
+            let half = self.pow(n / 2);
+            let squared = half.mul_m(&half);
+            if n % 2 == 1 { squared.mul_m(self) } else { squared }
+        }
+    }
+
     /// Transpose this matrix, returning a new matrix.
     #[must_use]
     fn transpose(&self) -> Self;
@@ -353,7 +691,9 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
 
     /// Invert this matrix, returning a new matrix. `m.mul_m(m.invert())` is
     /// the identity matrix. Returns `None` if this matrix is not invertible
-    /// (has a determinant of zero).
+    /// (has a determinant of approximately zero), or if any component of the
+    /// inverse would not be finite, as happens for matrices containing NaNs or
+    /// infinities.
     #[must_use]
     fn invert(&self) -> Option<Self>;
 
@@ -363,7 +703,9 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
         *self = self.invert().expect("Attempted to invert a matrix with zero determinant.");
     }
 
-    /// Test if this matrix is invertible.
+    /// Test if this matrix is invertible, that is, its determinant is not
+    /// approximately zero. This can be used to avoid calling `invert` on a
+    /// singular matrix.
     #[inline]
     fn is_invertible(&self) -> bool { !self.determinant().approx_eq(&zero()) }
 
@@ -376,8 +718,8 @@ pub trait Matrix<S: BaseFloat, V: Clone + Vector<S>>: Array2<V, V, S>
     /// the diagonal is 0.
     fn is_diagonal(&self) -> bool;
 
-    /// Test if this matrix is symmetric. That is, it is equal to its
-    /// transpose.
+    /// Test if this matrix is symmetric. That is, it is approximately equal to
+    /// its transpose.
     fn is_symmetric(&self) -> bool;
 }
 
@@ -486,6 +828,48 @@ impl<S: BaseFloat + 'static> Mul for Matrix4<S> {
     fn mul(self, other: Matrix4<S>) -> Matrix4<S> { self.mul_m(&other) }
 }
 
+impl<S: BaseFloat + 'static> Mul<Vector2<S>> for Matrix2<S> {
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, v: Vector2<S>) -> Vector2<S> { self.mul_v(&v) }
+}
+
+impl<S: BaseFloat + 'static> Mul<Vector3<S>> for Matrix3<S> {
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn mul(self, v: Vector3<S>) -> Vector3<S> { self.mul_v(&v) }
+}
+
+impl<S: BaseFloat + 'static> Mul<Vector4<S>> for Matrix4<S> {
+    type Output = Vector4<S>;
+
+    #[inline]
+    fn mul(self, v: Vector4<S>) -> Vector4<S> { self.mul_v(&v) }
+}
+
+impl<S: BaseFloat + 'static> Mul<S> for Matrix2<S> {
+    type Output = Matrix2<S>;
+
+    #[inline]
+    fn mul(self, s: S) -> Matrix2<S> { self.mul_s(s) }
+}
+
+impl<S: BaseFloat + 'static> Mul<S> for Matrix3<S> {
+    type Output = Matrix3<S>;
+
+    #[inline]
+    fn mul(self, s: S) -> Matrix3<S> { self.mul_s(s) }
+}
+
+impl<S: BaseFloat + 'static> Mul<S> for Matrix4<S> {
+    type Output = Matrix4<S>;
+
+    #[inline]
+    fn mul(self, s: S) -> Matrix4<S> { self.mul_s(s) }
+}
+
 impl<S: BaseFloat> One for Matrix2<S> {
     #[inline]
     fn one() -> Matrix2<S> { Matrix2::identity() }
@@ -498,6 +882,41 @@ impl<S: BaseFloat> One for Matrix4<S> {
     #[inline] fn one() -> Matrix4<S> { Matrix4::identity() }
 }
 
+/// The default matrix is the identity matrix.
+impl<S: BaseNum> Default for Matrix2<S> {
+    #[inline]
+    fn default() -> Matrix2<S> { Matrix2::identity() }
+}
+
+/// The default matrix is the identity matrix.
+impl<S: BaseNum> Default for Matrix3<S> {
+    #[inline]
+    fn default() -> Matrix3<S> { Matrix3::identity() }
+}
+
+/// The default matrix is the identity matrix.
+impl<S: BaseNum> Default for Matrix4<S> {
+    #[inline]
+    fn default() -> Matrix4<S> { Matrix4::identity() }
+}
+
+impl<S: NumCast + Copy> Matrix2<S> {
+    /// Component-wise casting to another type. Returns `None` if any
+    /// element can not be represented in the new type.
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Matrix2<T>> {
+        let x = match self.x.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        let y = match self.y.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        Some(Matrix2 { x: x, y: y })
+    }
+}
+
 impl<S> FixedArray<[[S; 2]; 2]> for Matrix2<S> {
     #[inline]
     fn into_fixed(self) -> [[S; 2]; 2] {
@@ -520,14 +939,13 @@ impl<S> FixedArray<[[S; 2]; 2]> for Matrix2<S> {
     }
 
     #[inline]
-    fn from_fixed(_v: [[S; 2]; 2]) -> Matrix2<S> {
-        // match v {
-        //     [x, y] => Matrix2 {
-        //         x: FixedArray::from_fixed(x),
-        //         y: FixedArray::from_fixed(y),
-        //     },
-        // }
-        panic!("Unimplemented, pending a fix for rust-lang/rust#16418");
+    fn from_fixed(v: [[S; 2]; 2]) -> Matrix2<S> {
+        // Works around rust-lang/rust#16418, as for the vector types.
+        unsafe {
+            let result: Matrix2<S> = ptr::read(FixedArray::from_fixed_ref(&v));
+            mem::forget(v);
+            result
+        }
     }
 
     #[inline]
@@ -578,6 +996,27 @@ impl<S: Copy + 'static> Array2<Vector2<S>, Vector2<S>, S> for Matrix2<S> {
     }
 }
 
+impl<S: NumCast + Copy> Matrix3<S> {
+    /// Component-wise casting to another type. Returns `None` if any
+    /// element can not be represented in the new type.
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Matrix3<T>> {
+        let x = match self.x.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        let y = match self.y.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        let z = match self.z.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        Some(Matrix3 { x: x, y: y, z: z })
+    }
+}
+
 impl<S> FixedArray<[[S; 3]; 3]> for Matrix3<S> {
     #[inline]
     fn into_fixed(self) -> [[S; 3]; 3] {
@@ -601,15 +1040,13 @@ impl<S> FixedArray<[[S; 3]; 3]> for Matrix3<S> {
     }
 
     #[inline]
-    fn from_fixed(_v: [[S; 3]; 3]) -> Matrix3<S> {
-        // match v {
-        //     [x, y, z] => Matrix3 {
-        //         x: FixedArray::from_fixed(x),
-        //         y: FixedArray::from_fixed(y),
-        //         z: FixedArray::from_fixed(z),
-        //     },
-        // }
-        panic!("Unimplemented, pending a fix for rust-lang/rust#16418")
+    fn from_fixed(v: [[S; 3]; 3]) -> Matrix3<S> {
+        // Works around rust-lang/rust#16418, as for the vector types.
+        unsafe {
+            let result: Matrix3<S> = ptr::read(FixedArray::from_fixed_ref(&v));
+            mem::forget(v);
+            result
+        }
     }
 
     #[inline]
@@ -663,6 +1100,31 @@ impl<S: Copy + 'static> Array2<Vector3<S>, Vector3<S>, S> for Matrix3<S> {
     }
 }
 
+impl<S: NumCast + Copy> Matrix4<S> {
+    /// Component-wise casting to another type. Returns `None` if any
+    /// element can not be represented in the new type.
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Matrix4<T>> {
+        let x = match self.x.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        let y = match self.y.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        let z = match self.z.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        let w = match self.w.cast() {
+            Some(c) => c,
+            None => return None,
+        };
+        Some(Matrix4 { x: x, y: y, z: z, w: w })
+    }
+}
+
 impl<S> FixedArray<[[S; 4]; 4]> for Matrix4<S> {
     #[inline]
     fn into_fixed(self) -> [[S; 4]; 4] {
@@ -687,16 +1149,13 @@ impl<S> FixedArray<[[S; 4]; 4]> for Matrix4<S> {
     }
 
     #[inline]
-    fn from_fixed(_v: [[S; 4]; 4]) -> Matrix4<S> {
-        // match v {
-        //     [x, y, z, w] => Matrix4 {
-        //         x: FixedArray::from_fixed(x),
-        //         y: FixedArray::from_fixed(y),
-        //         z: FixedArray::from_fixed(z),
-        //         w: FixedArray::from_fixed(w),
-        //     },
-        // }
-        panic!("Unimplemented, pending a fix for rust-lang/rust#16418")
+    fn from_fixed(v: [[S; 4]; 4]) -> Matrix4<S> {
+        // Works around rust-lang/rust#16418, as for the vector types.
+        unsafe {
+            let result: Matrix4<S> = ptr::read(FixedArray::from_fixed_ref(&v));
+            mem::forget(v);
+            result
+        }
     }
 
     #[inline]
@@ -858,8 +1317,9 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector2<S>> for Matrix2<S> {
         if det.approx_eq(&zero()) {
             None
         } else {
-            Some(Matrix2::new( self[1][1] / det, -self[0][1] / det,
-                              -self[1][0] / det,  self[0][0] / det))
+            let inv = Matrix2::new( self[1][1] / det, -self[0][1] / det,
+                                   -self[1][0] / det,  self[0][0] / det);
+            if inv.is_finite() { Some(inv) } else { None }
         }
     }
 
@@ -997,9 +1457,10 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector3<S>> for Matrix3<S> {
     fn invert(&self) -> Option<Matrix3<S>> {
         let det = self.determinant();
         if det.approx_eq(&zero()) { None } else {
-            Some(Matrix3::from_cols(self[1].cross(&self[2]).div_s(det),
-                                    self[2].cross(&self[0]).div_s(det),
-                                    self[0].cross(&self[1]).div_s(det)).transpose())
+            let inv = Matrix3::from_cols(self[1].cross(&self[2]).div_s(det),
+                                         self[2].cross(&self[0]).div_s(det),
+                                         self[0].cross(&self[1]).div_s(det)).transpose();
+            if inv.is_finite() { Some(inv) } else { None }
         }
     }
 
@@ -1212,10 +1673,11 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector4<S>> for Matrix4<S> {
                 mat.determinant() * sign * inv_det
             };
 
-            Some(Matrix4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
-                              cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
-                              cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
-                              cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3)))
+            let inv = Matrix4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
+                                   cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
+                                   cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
+                                   cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3));
+            if inv.is_finite() { Some(inv) } else { None }
 
         } else {
             None
@@ -1259,12 +1721,156 @@ impl<S: BaseFloat + 'static> Matrix<S, Vector4<S>> for Matrix4<S> {
     }
 }
 
+/// Solve `m * x = b` for `x`, where `m` is an `n` by `n` matrix, by LU
+/// decomposition with partial pivoting. The factors are not stored, as the
+/// elimination is applied to `b` as it goes.
+fn solve_lu<S, V, M>(m: &M, b: &V, n: usize) -> Option<V>
+    where S: BaseFloat, V: Clone + Vector<S> + 'static, M: Matrix<S, V> + Clone {
+    let mut a = m.clone();
+    let mut x = b.clone();
+
+    for k in 0..n {
+        // pick the row with the largest pivot to keep the elimination stable
+        let mut p = k;
+        for r in k + 1..n {
+            if a[k][r].abs() > a[k][p].abs() { p = r; }
+        }
+        if a[k][p].approx_eq(&zero()) { return None; }
+        if p != k {
+            a.swap_rows(k, p);
+            Array1::swap_elems(&mut x, k, p);
+        }
+
+        for r in k + 1..n {
+            let f = a[k][r] / a[k][k];
+            for c in k..n {
+                let v = a[c][k];
+                a[c][r] = a[c][r] - f * v;
+            }
+            let v = x[k];
+            x[r] = x[r] - f * v;
+        }
+    }
+
+    // back substitution through the upper triangular factor
+    for k in (0..n).rev() {
+        let mut s = x[k];
+        for c in k + 1..n { s = s - a[c][k] * x[c]; }
+        x[k] = s / a[k][k];
+    }
+    Some(x)
+}
+
+impl<S: BaseFloat + 'static> Matrix2<S> {
+    /// Solve the linear system `self * x = b` for `x`, using LU decomposition
+    /// with partial pivoting. This is more accurate than multiplying `b` by
+    /// the inverse. Returns `None` if the matrix is singular.
+    pub fn solve(&self, b: &Vector2<S>) -> Option<Vector2<S>> { solve_lu(self, b, 2) }
+}
+
+impl<S: BaseFloat + 'static> Matrix3<S> {
+    /// Solve the linear system `self * x = b` for `x`, using LU decomposition
+    /// with partial pivoting. This is more accurate than multiplying `b` by
+    /// the inverse. Returns `None` if the matrix is singular.
+    pub fn solve(&self, b: &Vector3<S>) -> Option<Vector3<S>> { solve_lu(self, b, 3) }
+
+    /// Find the eigenvalues and eigenvectors of a symmetric matrix, using the
+    /// cyclic Jacobi method. Only the lower triangle of the matrix is read.
+    ///
+    /// The eigenvalues are returned in no particular order, and the `i`th
+    /// column of the returned matrix is the unit eigenvector for the `i`th
+    /// eigenvalue. The eigenvectors are built up from plane rotations, so
+    /// they stay orthonormal even when eigenvalues are close together.
+    pub fn symmetric_eigen(&self) -> (Vector3<S>, Matrix3<S>) {
+        let mut a = *self;
+        for c in 1..3 {
+            for r in 0..c { a[c][r] = a[r][c]; }
+        }
+        let mut v = Matrix3::identity();
+
+        let two = one::<S>() + one::<S>();
+        let hundred: S = cast(100i8).unwrap();
+        for _ in 0..32 {
+            let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off == zero() { break; }
+
+            for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+                let apq = a[p][q];
+
+                // once `a[p][q]` is too small to change either diagonal
+                // element, it can be dropped
+                let g = hundred * apq.abs();
+                if a[p][p].abs() + g == a[p][p].abs() && a[q][q].abs() + g == a[q][q].abs() {
+                    a[p][q] = zero();
+                    a[q][p] = zero();
+                    continue;
+                }
+
+                // choose the smaller of the two rotation angles that zero
+                // `a[p][q]`, see _Numerical Recipes_, section 11.1
+                let theta = (a[q][q] - a[p][p]) / (two * apq);
+                let t = theta.signum() / (theta.abs() + theta.hypot(one()));
+                let c = one::<S>() / t.hypot(one());
+                let s = t * c;
+
+                let mut j: Matrix3<S> = Matrix3::identity();
+                j[p][p] = c;
+                j[q][q] = c;
+                j[q][p] = s;
+                j[p][q] = -s;
+
+                a = j.transpose().mul_m(&a).mul_m(&j);
+                a[p][q] = zero();
+                a[q][p] = zero();
+                v = v.mul_m(&j);
+            }
+        }
+
+        (a.diagonal(), v)
+    }
+}
+
+impl<S: BaseFloat + 'static> Matrix4<S> {
+    /// Solve the linear system `self * x = b` for `x`, using LU decomposition
+    /// with partial pivoting. This is more accurate than multiplying `b` by
+    /// the inverse. Returns `None` if the matrix is singular.
+    pub fn solve(&self, b: &Vector4<S>) -> Option<Vector4<S>> { solve_lu(self, b, 4) }
+}
+
 impl<S: BaseFloat> ApproxEq<S> for Matrix2<S> {
     #[inline]
     fn approx_eq_eps(&self, other: &Matrix2<S>, epsilon: &S) -> bool {
         self[0].approx_eq_eps(&other[0], epsilon) &&
         self[1].approx_eq_eps(&other[1], epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Matrix2<S>, ulps: u32) -> bool {
+        self[0].approx_eq_ulps(&other[0], ulps) &&
+        self[1].approx_eq_ulps(&other[1], ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Matrix2<S>, epsilon: &S, max_relative: &S) -> bool {
+        self[0].relative_eq_eps(&other[0], epsilon, max_relative) &&
+        self[1].relative_eq_eps(&other[1], epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> Matrix2<S> {
+    /// Returns `true` if every component of the matrix is finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self[0].is_finite() &&
+        self[1].is_finite()
+    }
+
+    /// Returns `true` if any component of the matrix is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self[0].is_nan() ||
+        self[1].is_nan()
+    }
 }
 
 impl<S: BaseFloat> ApproxEq<S> for Matrix3<S> {
@@ -1274,6 +1880,38 @@ impl<S: BaseFloat> ApproxEq<S> for Matrix3<S> {
         self[1].approx_eq_eps(&other[1], epsilon) &&
         self[2].approx_eq_eps(&other[2], epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Matrix3<S>, ulps: u32) -> bool {
+        self[0].approx_eq_ulps(&other[0], ulps) &&
+        self[1].approx_eq_ulps(&other[1], ulps) &&
+        self[2].approx_eq_ulps(&other[2], ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Matrix3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self[0].relative_eq_eps(&other[0], epsilon, max_relative) &&
+        self[1].relative_eq_eps(&other[1], epsilon, max_relative) &&
+        self[2].relative_eq_eps(&other[2], epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> Matrix3<S> {
+    /// Returns `true` if every component of the matrix is finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self[0].is_finite() &&
+        self[1].is_finite() &&
+        self[2].is_finite()
+    }
+
+    /// Returns `true` if any component of the matrix is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self[0].is_nan() ||
+        self[1].is_nan() ||
+        self[2].is_nan()
+    }
 }
 
 impl<S: BaseFloat> ApproxEq<S> for Matrix4<S> {
@@ -1284,6 +1922,42 @@ impl<S: BaseFloat> ApproxEq<S> for Matrix4<S> {
         self[2].approx_eq_eps(&other[2], epsilon) &&
         self[3].approx_eq_eps(&other[3], epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Matrix4<S>, ulps: u32) -> bool {
+        self[0].approx_eq_ulps(&other[0], ulps) &&
+        self[1].approx_eq_ulps(&other[1], ulps) &&
+        self[2].approx_eq_ulps(&other[2], ulps) &&
+        self[3].approx_eq_ulps(&other[3], ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Matrix4<S>, epsilon: &S, max_relative: &S) -> bool {
+        self[0].relative_eq_eps(&other[0], epsilon, max_relative) &&
+        self[1].relative_eq_eps(&other[1], epsilon, max_relative) &&
+        self[2].relative_eq_eps(&other[2], epsilon, max_relative) &&
+        self[3].relative_eq_eps(&other[3], epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Returns `true` if every component of the matrix is finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self[0].is_finite() &&
+        self[1].is_finite() &&
+        self[2].is_finite() &&
+        self[3].is_finite()
+    }
+
+    /// Returns `true` if any component of the matrix is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self[0].is_nan() ||
+        self[1].is_nan() ||
+        self[2].is_nan() ||
+        self[3].is_nan()
+    }
 }
 
 // Conversion traits
@@ -1339,13 +2013,20 @@ impl<S: BaseFloat> ToMatrix4<S> for Matrix3<S> {
 }
 
 impl<S: BaseFloat + 'static> ToQuaternion<S> for Matrix3<S> {
-    /// Convert the matrix to a quaternion
+    /// Convert the matrix to a quaternion. The matrix is assumed to be a
+    /// rotation matrix.
+    ///
+    /// This uses Shepperd's method: whichever of `w`, `x`, `y` and `z` has the
+    /// largest magnitude is computed from the diagonal, and the remaining
+    /// components are derived from it. This keeps the square root argument
+    /// well away from zero, even when the trace is negative.
     fn to_quaternion(&self) -> Quaternion<S> {
         // http://www.cs.ucr.edu/~vbz/resources/quatut.pdf
         let trace = self.trace();
         let half: S = cast(0.5f64).unwrap();
+        let (m00, m11, m22) = (self[0][0], self[1][1], self[2][2]);
 
-        if trace >= zero::<S>() {
+        if trace >= m00 && trace >= m11 && trace >= m22 {
             let s = (one::<S>() + trace).sqrt();
             let w = half * s;
             let s = half / s;
@@ -1353,29 +2034,29 @@ impl<S: BaseFloat + 'static> ToQuaternion<S> for Matrix3<S> {
             let y = (self[2][0] - self[0][2]) * s;
             let z = (self[0][1] - self[1][0]) * s;
             Quaternion::new(w, x, y, z)
-        } else if (self[0][0] > self[1][1]) && (self[0][0] > self[2][2]) {
-            let s = (half + (self[0][0] - self[1][1] - self[2][2])).sqrt();
-            let w = half * s;
+        } else if m00 >= m11 && m00 >= m22 {
+            let s = (one::<S>() + m00 - m11 - m22).sqrt();
+            let x = half * s;
             let s = half / s;
-            let x = (self[0][1] - self[1][0]) * s;
-            let y = (self[2][0] - self[0][2]) * s;
-            let z = (self[1][2] - self[2][1]) * s;
+            let w = (self[1][2] - self[2][1]) * s;
+            let y = (self[1][0] + self[0][1]) * s;
+            let z = (self[2][0] + self[0][2]) * s;
             Quaternion::new(w, x, y, z)
-        } else if self[1][1] > self[2][2] {
-            let s = (half + (self[1][1] - self[0][0] - self[2][2])).sqrt();
-            let w = half * s;
+        } else if m11 >= m22 {
+            let s = (one::<S>() + m11 - m00 - m22).sqrt();
+            let y = half * s;
             let s = half / s;
-            let x = (self[0][1] - self[1][0]) * s;
-            let y = (self[1][2] - self[2][1]) * s;
-            let z = (self[2][0] - self[0][2]) * s;
+            let w = (self[2][0] - self[0][2]) * s;
+            let x = (self[1][0] + self[0][1]) * s;
+            let z = (self[2][1] + self[1][2]) * s;
             Quaternion::new(w, x, y, z)
         } else {
-            let s = (half + (self[2][2] - self[0][0] - self[1][1])).sqrt();
-            let w = half * s;
+            let s = (one::<S>() + m22 - m00 - m11).sqrt();
+            let z = half * s;
             let s = half / s;
-            let x = (self[2][0] - self[0][2]) * s;
-            let y = (self[1][2] - self[2][1]) * s;
-            let z = (self[0][1] - self[1][0]) * s;
+            let w = (self[0][1] - self[1][0]) * s;
+            let x = (self[2][0] + self[0][2]) * s;
+            let y = (self[2][1] + self[1][2]) * s;
             Quaternion::new(w, x, y, z)
         }
     }
@@ -17,9 +17,9 @@ use std::fmt;
 
 use approx::ApproxEq;
 use intersect::Intersect;
-use num::{BaseFloat, one, Zero, zero};
+use num::{BaseFloat, one, zero};
 use point::{Point, Point3};
-use ray::Ray3;
+use ray::{Ray, Ray3};
 use vector::{Vector3, Vector4};
 use vector::{Vector, EuclideanVector};
 
@@ -87,7 +87,7 @@ impl<S: BaseFloat> Plane<S> {
         else {
             // compute the normal and the distance to the plane
             n.normalize_self();
-            let d = -a.dot(&n);
+            let d = a.dot(&n);
 
             Some(Plane::new(n, d))
         }
@@ -99,6 +99,69 @@ impl<S: BaseFloat> Plane<S> {
         Plane { n: n, d: p.dot(&n) }
     }
 
+    /// Compute the signed distance from the plane to `p`. The result is
+    /// positive if `p` is on the side of the plane that the normal points
+    /// towards, and negative if it is on the other side. This assumes that the
+    /// plane's normal is a unit vector.
+    #[inline]
+    pub fn distance_to_point(&self, p: &Point3<S>) -> S {
+        p.dot(&self.n) - self.d
+    }
+
+    /// Project `p` orthogonally onto the plane, giving the point on the plane
+    /// closest to `p`. This assumes that the plane's normal is a unit vector.
+    #[inline]
+    pub fn project_point(&self, p: &Point3<S>) -> Point3<S> {
+        p.sub_v(&self.n.mul_s(self.distance_to_point(p)))
+    }
+
+    /// Mirror `p` across the plane. This assumes that the plane's normal is a
+    /// unit vector.
+    #[inline]
+    pub fn reflect_point(&self, p: &Point3<S>) -> Point3<S> {
+        let two = one::<S>() + one::<S>();
+        p.sub_v(&self.n.mul_s(two * self.distance_to_point(p)))
+    }
+
+    /// Find the point at which `ray` hits the plane, or `None` if it points
+    /// away from the plane or runs parallel to it.
+    pub fn intersection_ray(&self, ray: &Ray3<S>) -> Option<Point3<S>> {
+        let denom = ray.direction.dot(&self.n);
+        if denom.approx_eq(&zero()) { return None; }
+
+        let t = (self.d - ray.origin.dot(&self.n)) / denom;
+        if t < zero() { None }
+        else { Some(ray.origin.add_v(&ray.direction.mul_s(t))) }
+    }
+
+    /// Find the line along which this plane meets `other`, or `None` if the
+    /// planes are parallel. The direction of the resulting ray is the cross
+    /// product of the two normals, and is not normalized.
+    pub fn intersection_plane(&self, other: &Plane<S>) -> Option<Ray3<S>> {
+        let dir = self.n.cross(&other.n);
+        let denom = dir.dot(&dir);
+        if denom.approx_eq(&zero()) { return None; }
+
+        // the point on the line closest to the origin,
+        // see _Real Time Collision Detection_, p. 208
+        let v = other.n.mul_s(self.d).sub_v(&self.n.mul_s(other.d));
+        let origin = Point::from_vec(&v.cross(&dir).div_s(denom));
+        Some(Ray::new(origin, dir))
+    }
+
+    /// Find the single point at which the planes `a`, `b` and `c` meet, using
+    /// Cramer's rule. Returns `None` if any two of the planes are parallel, or
+    /// if the three planes meet along a line.
+    pub fn intersection_3(a: &Plane<S>, b: &Plane<S>, c: &Plane<S>) -> Option<Point3<S>> {
+        let u = b.n.cross(&c.n);
+        let denom = a.n.dot(&u);
+        if denom.approx_eq(&zero()) { return None; }
+
+        // see _Real Time Collision Detection_, p. 212
+        let v = a.n.cross(&b.n.mul_s(c.d).sub_v(&c.n.mul_s(b.d)));
+        Some(Point::from_vec(&u.mul_s(a.d).add_v(&v).div_s(denom)))
+    }
+
     /// Normalize a plane.
     pub fn normalize(&self) -> Option<Plane<S>> {
         if self.n.approx_eq(&zero()) { None }
@@ -112,22 +175,21 @@ impl<S: BaseFloat> Plane<S> {
 impl<S: BaseFloat> Intersect<Option<Point3<S>>> for (Plane<S>, Ray3<S>) {
     fn intersection(&self) -> Option<Point3<S>> {
         let (ref p, ref r) = *self;
-
-        let t = -(p.d + r.origin.dot(&p.n)) / r.direction.dot(&p.n);
-        if t < Zero::zero() { None }
-        else { Some(r.origin.add_v(&r.direction.mul_s(t))) }
+        p.intersection_ray(r)
     }
 }
 
 impl<S: BaseFloat> Intersect<Option<Ray3<S>>> for (Plane<S>, Plane<S>) {
     fn intersection(&self) -> Option<Ray3<S>> {
-        panic!("Not yet implemented");
+        let (ref p0, ref p1) = *self;
+        p0.intersection_plane(p1)
     }
 }
 
 impl<S: BaseFloat> Intersect<Option<Point3<S>>> for (Plane<S>, Plane<S>, Plane<S>) {
     fn intersection(&self) -> Option<Point3<S>> {
-        panic!("Not yet implemented");
+        let (ref p0, ref p1, ref p2) = *self;
+        Plane::intersection_3(p0, p1, p2)
     }
 }
 
@@ -138,6 +200,18 @@ ApproxEq<S> for Plane<S> {
         self.n.approx_eq_eps(&other.n, epsilon) &&
         self.d.approx_eq_eps(&other.d, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Plane<S>, ulps: u32) -> bool {
+        self.n.approx_eq_ulps(&other.n, ulps) &&
+        self.d.approx_eq_ulps(&other.d, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Plane<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.n.relative_eq_eps(&other.n, epsilon, max_relative) &&
+        self.d.relative_eq_eps(&other.d, epsilon, max_relative)
+    }
 }
 
 impl<S: BaseFloat> fmt::Debug for Plane<S> {
@@ -17,8 +17,12 @@
 //! disinguishes them from vectors, which have a length and direction, but do
 //! not have a fixed position.
 
+use std::default::Default;
 use std::fmt;
+use std::iter::{FromIterator, IntoIterator};
 use std::mem;
+use std::num::{NumCast, cast};
+use std::ptr;
 use std::ops::*;
 
 use approx::ApproxEq;
@@ -30,11 +34,17 @@ use plane::Plane;
 use vector::*;
 
 /// A point in 2-dimensional space.
-#[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
 pub struct Point2<S> { pub x: S, pub y: S }
 
 /// A point in 3-dimensional space.
-#[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
 pub struct Point3<S> { pub x: S, pub y: S, pub z: S }
 
 
@@ -43,6 +53,18 @@ impl<S: BaseNum> Point2<S> {
     pub fn new(x: S, y: S) -> Point2<S> {
         Point2 { x: x, y: y }
     }
+
+    /// View the coordinates of the point as a slice, in `x, y` order.
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [S] {
+        &self.as_fixed()[..]
+    }
+
+    /// View the coordinates of the point as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [S] {
+        &mut self.as_mut_fixed()[..]
+    }
 }
 
 impl<S: BaseNum> Point3<S> {
@@ -50,6 +72,18 @@ impl<S: BaseNum> Point3<S> {
     pub fn new(x: S, y: S, z: S) -> Point3<S> {
         Point3 { x: x, y: y, z: z }
     }
+
+    /// View the coordinates of the point as a slice, in `x, y, z` order.
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [S] {
+        &self.as_fixed()[..]
+    }
+
+    /// View the coordinates of the point as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [S] {
+        &mut self.as_mut_fixed()[..]
+    }
 }
 
 impl<S: BaseNum> Point3<S> {
@@ -88,6 +122,9 @@ pub trait Point<S: BaseNum, V: Vector<S>>: Array1<S> + Clone {
     /// Add a vector to this point, returning the new point.
     #[must_use]
     fn add_v(&self, v: &V) -> Self;
+    /// Subtract a vector from this point, returning the new point.
+    #[must_use]
+    fn sub_v(&self, v: &V) -> Self;
     /// Subtract another point from this one, returning a new vector.
     fn sub_p(&self, p: &Self) -> V;
 
@@ -100,6 +137,8 @@ pub trait Point<S: BaseNum, V: Vector<S>>: Array1<S> + Clone {
 
     /// Add a vector to this point, in-place.
     fn add_self_v(&mut self, v: &V);
+    /// Subtract a vector from this point, in-place.
+    fn sub_self_v(&mut self, v: &V);
 
     /// This is a weird one, but its useful for plane calculations.
     fn dot(&self, v: &V) -> S;
@@ -109,6 +148,56 @@ pub trait Point<S: BaseNum, V: Vector<S>>: Array1<S> + Clone {
 
     #[must_use]
     fn max(&self, p: &Self) -> Self;
+
+    /// The squared distance between this point and another. This is cheaper
+    /// than `distance` and is sufficient for comparing distances.
+    #[inline]
+    fn distance2(&self, p: &Self) -> S {
+        let v = self.sub_p(p);
+        v.dot(&v)
+    }
+
+    /// The Euclidean distance between this point and another.
+    #[inline]
+    fn distance(&self, p: &Self) -> S where S: BaseFloat, V: EuclideanVector<S> {
+        self.sub_p(p).length()
+    }
+
+    /// The point halfway between this point and another.
+    #[inline]
+    fn midpoint(&self, p: &Self) -> Self {
+        let two = one::<S>() + one::<S>();
+        self.add_v(&p.sub_p(self).div_s(two))
+    }
+}
+
+/// The centroid of a set of points, that is, their average position.
+///
+/// # Panics
+///
+/// Panics if `points` is empty, as the centroid is then undefined.
+pub fn centroid<S: BaseNum, V: Vector<S>, P: Point<S, V>>(points: &[P]) -> P {
+    assert!(!points.is_empty(), "The centroid of no points is undefined");
+    let n: S = cast(points.len()).unwrap();
+    let total = points.iter().fold(zero::<V>(), |acc, p| acc.add_v(&p.to_vec()));
+    Point::from_vec(&total.div_s(n))
+}
+
+impl<S: NumCast + Copy> Point2<S> {
+    /// Component-wise casting to another type. Returns `None` if any
+    /// coordinate can not be represented in the new type.
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Point2<T>> {
+        let x = match NumCast::from(self.x) {
+            Some(v) => v,
+            None => return None,
+        };
+        let y = match NumCast::from(self.y) {
+            Some(v) => v,
+            None => return None,
+        };
+        Some(Point2 { x: x, y: y })
+    }
 }
 
 impl<S> FixedArray<[S; 2]> for Point2<S> {
@@ -128,9 +217,13 @@ impl<S> FixedArray<[S; 2]> for Point2<S> {
     }
 
     #[inline]
-    fn from_fixed(_v: [S; 2]) -> Point2<S> {
-        // match v { [x, y] => Point2 { x: x, y: y } }
-        panic!("Unimplemented, pending a fix for rust-lang/rust#16418");
+    fn from_fixed(v: [S; 2]) -> Point2<S> {
+        // Works around rust-lang/rust#16418, as for the vector types.
+        unsafe {
+            let result: Point2<S> = ptr::read(FixedArray::from_fixed_ref(&v));
+            mem::forget(v);
+            result
+        }
     }
 
     #[inline]
@@ -144,6 +237,22 @@ impl<S> FixedArray<[S; 2]> for Point2<S> {
     }
 }
 
+/// Collect the coordinates of the point from an iterator. Any items left over
+/// are not consumed.
+///
+/// # Panics
+///
+/// If the iterator yields too few items.
+impl<S> FromIterator<S> for Point2<S> {
+    fn from_iter<I: IntoIterator<Item=S>>(iter: I) -> Point2<S> {
+        let mut iter = iter.into_iter();
+        Point2 {
+            x: iter.next().expect("Too few items to collect into a Point2"),
+            y: iter.next().expect("Too few items to collect into a Point2"),
+        }
+    }
+}
+
 impl<S: BaseNum> Index<usize> for Point2<S> {
     type Output = S;
     #[inline]
@@ -209,6 +318,12 @@ impl<S: BaseNum> Point<S, Vector2<S>> for Point2<S> {
                     self.y + v.y)
     }
 
+    #[inline]
+    fn sub_v(&self, v: &Vector2<S>) -> Point2<S> {
+        Point2::new(self.x - v.x,
+                    self.y - v.y)
+    }
+
     #[inline]
     fn sub_p(&self, p: &Point2<S>) -> Vector2<S> {
         Vector2::new(self.x - p.x,
@@ -239,6 +354,12 @@ impl<S: BaseNum> Point<S, Vector2<S>> for Point2<S> {
         self.y = self.y + v.y;
     }
 
+    #[inline]
+    fn sub_self_v(&mut self, v: &Vector2<S>) {
+        self.x = self.x - v.x;
+        self.y = self.y - v.y;
+    }
+
     #[inline]
     fn dot(&self, v: &Vector2<S>) -> S {
         self.x * v.x +
@@ -264,6 +385,55 @@ impl<S: BaseFloat> ApproxEq<S> for Point2<S> {
         self.x.approx_eq_eps(&other.x, epsilon) &&
         self.y.approx_eq_eps(&other.y, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Point2<S>, ulps: u32) -> bool {
+        self.x.approx_eq_ulps(&other.x, ulps) &&
+        self.y.approx_eq_ulps(&other.y, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Point2<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.x.relative_eq_eps(&other.x, epsilon, max_relative) &&
+        self.y.relative_eq_eps(&other.y, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> Point2<S> {
+    /// Returns `true` if every component of the point is finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() &&
+        self.y.is_finite()
+    }
+
+    /// Returns `true` if any component of the point is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() ||
+        self.y.is_nan()
+    }
+}
+
+impl<S: NumCast + Copy> Point3<S> {
+    /// Component-wise casting to another type. Returns `None` if any
+    /// coordinate can not be represented in the new type.
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Option<Point3<T>> {
+        let x = match NumCast::from(self.x) {
+            Some(v) => v,
+            None => return None,
+        };
+        let y = match NumCast::from(self.y) {
+            Some(v) => v,
+            None => return None,
+        };
+        let z = match NumCast::from(self.z) {
+            Some(v) => v,
+            None => return None,
+        };
+        Some(Point3 { x: x, y: y, z: z })
+    }
 }
 
 impl<S> FixedArray<[S; 3]> for Point3<S> {
@@ -283,9 +453,13 @@ impl<S> FixedArray<[S; 3]> for Point3<S> {
     }
 
     #[inline]
-    fn from_fixed(_v: [S; 3]) -> Point3<S> {
-        // match v { [x, y, z] => Point3 { x: x, y: y, z: z } }
-        panic!("Unimplemented, pending a fix for rust-lang/rust#16418")
+    fn from_fixed(v: [S; 3]) -> Point3<S> {
+        // Works around rust-lang/rust#16418, as for the vector types.
+        unsafe {
+            let result: Point3<S> = ptr::read(FixedArray::from_fixed_ref(&v));
+            mem::forget(v);
+            result
+        }
     }
 
     #[inline]
@@ -299,6 +473,23 @@ impl<S> FixedArray<[S; 3]> for Point3<S> {
     }
 }
 
+/// Collect the coordinates of the point from an iterator. Any items left over
+/// are not consumed.
+///
+/// # Panics
+///
+/// If the iterator yields too few items.
+impl<S> FromIterator<S> for Point3<S> {
+    fn from_iter<I: IntoIterator<Item=S>>(iter: I) -> Point3<S> {
+        let mut iter = iter.into_iter();
+        Point3 {
+            x: iter.next().expect("Too few items to collect into a Point3"),
+            y: iter.next().expect("Too few items to collect into a Point3"),
+            z: iter.next().expect("Too few items to collect into a Point3"),
+        }
+    }
+}
+
 impl<S: BaseNum> Index<usize> for Point3<S> {
     type Output = S;
 
@@ -371,6 +562,13 @@ impl<S: BaseNum> Point<S, Vector3<S>> for Point3<S> {
                     self.z + v.z)
     }
 
+    #[inline]
+    fn sub_v(&self, v: &Vector3<S>) -> Point3<S> {
+        Point3::new(self.x - v.x,
+                    self.y - v.y,
+                    self.z - v.z)
+    }
+
     #[inline]
     fn sub_p(&self, p: &Point3<S>) -> Vector3<S> {
         Vector3::new(self.x - p.x,
@@ -406,6 +604,13 @@ impl<S: BaseNum> Point<S, Vector3<S>> for Point3<S> {
         self.z = self.z + v.z;
     }
 
+    #[inline]
+    fn sub_self_v(&mut self, v: &Vector3<S>) {
+        self.x = self.x - v.x;
+        self.y = self.y - v.y;
+        self.z = self.z - v.z;
+    }
+
     #[inline]
     fn dot(&self, v: &Vector3<S>) -> S {
         self.x * v.x +
@@ -435,6 +640,106 @@ impl<S: BaseFloat> ApproxEq<S> for Point3<S> {
         self.y.approx_eq_eps(&other.y, epsilon) &&
         self.z.approx_eq_eps(&other.z, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Point3<S>, ulps: u32) -> bool {
+        self.x.approx_eq_ulps(&other.x, ulps) &&
+        self.y.approx_eq_ulps(&other.y, ulps) &&
+        self.z.approx_eq_ulps(&other.z, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Point3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.x.relative_eq_eps(&other.x, epsilon, max_relative) &&
+        self.y.relative_eq_eps(&other.y, epsilon, max_relative) &&
+        self.z.relative_eq_eps(&other.z, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> Point3<S> {
+    /// Returns `true` if every component of the point is finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() &&
+        self.y.is_finite() &&
+        self.z.is_finite()
+    }
+
+    /// Returns `true` if any component of the point is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() ||
+        self.y.is_nan() ||
+        self.z.is_nan()
+    }
+}
+
+/// The default point is the origin.
+impl<S: BaseNum> Default for Point2<S> {
+    #[inline]
+    fn default() -> Point2<S> { Point::origin() }
+}
+
+/// The default point is the origin.
+impl<S: BaseNum> Default for Point3<S> {
+    #[inline]
+    fn default() -> Point3<S> { Point::origin() }
+}
+
+impl<S: BaseNum> Add<Vector2<S>> for Point2<S> {
+    type Output = Point2<S>;
+
+    #[inline]
+    fn add(self, v: Vector2<S>) -> Point2<S> { self.add_v(&v) }
+}
+
+impl<S: BaseNum> Sub<Vector2<S>> for Point2<S> {
+    type Output = Point2<S>;
+
+    #[inline]
+    fn sub(self, v: Vector2<S>) -> Point2<S> { self.sub_v(&v) }
+}
+
+impl<S: BaseNum> Sub<Point2<S>> for Point2<S> {
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn sub(self, p: Point2<S>) -> Vector2<S> { self.sub_p(&p) }
+}
+
+impl<S: BaseNum> Mul<S> for Point2<S> {
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, s: S) -> Point2<S> { self.mul_s(s) }
+}
+
+impl<S: BaseNum> Add<Vector3<S>> for Point3<S> {
+    type Output = Point3<S>;
+
+    #[inline]
+    fn add(self, v: Vector3<S>) -> Point3<S> { self.add_v(&v) }
+}
+
+impl<S: BaseNum> Sub<Vector3<S>> for Point3<S> {
+    type Output = Point3<S>;
+
+    #[inline]
+    fn sub(self, v: Vector3<S>) -> Point3<S> { self.sub_v(&v) }
+}
+
+impl<S: BaseNum> Sub<Point3<S>> for Point3<S> {
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn sub(self, p: Point3<S>) -> Vector3<S> { self.sub_p(&p) }
+}
+
+impl<S: BaseNum> Mul<S> for Point3<S> {
+    type Output = Point3<S>;
+
+    #[inline]
+    fn mul(self, s: S) -> Point3<S> { self.mul_s(s) }
 }
 
 impl<S: BaseNum> fmt::Debug for Point2<S> {
@@ -17,14 +17,23 @@ use std::num::cast;
 
 use angle::{Angle, tan, cot};
 use frustum::Frustum;
-use matrix::{Matrix4, ToMatrix4};
+use matrix::{Matrix, Matrix4, ToMatrix4};
 use num::{BaseFloat, zero, one};
 use plane::Plane;
+use point::{Point, Point2, Point3};
+use ray::{Ray, Ray3};
+use vector::{EuclideanVector, Vector4};
 
-/// Create a perspective projection matrix.
+/// Create a perspective projection matrix from a vertical field of view and
+/// an aspect ratio.
 ///
 /// This is the equivalent to the [gluPerspective]
 /// (http://www.opengl.org/sdk/docs/man2/xhtml/gluPerspective.xml) function.
+///
+/// # Panics
+///
+/// If `fovy` is not between zero and a half turn, `aspect` is not positive,
+/// `near` is not positive or `far` is not beyond `near`.
 pub fn perspective<S: BaseFloat + 'static, A: Angle<S>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
     PerspectiveFov {
         fovy:   fovy,
@@ -34,6 +43,34 @@ pub fn perspective<S: BaseFloat + 'static, A: Angle<S>>(fovy: A, aspect: S, near
     }.to_matrix4()
 }
 
+/// Create a perspective projection matrix with the far plane at infinity.
+///
+/// This is the limit of `perspective` as `far` tends to infinity. Points at
+/// any distance beyond the near plane are kept, with the depth approaching
+/// the far end of the clip range as the distance grows, so no depth precision
+/// is spent on an arbitrary far plane.
+///
+/// # Panics
+///
+/// If `fovy` is not between zero and a half turn, or `aspect` or `near` are
+/// not positive.
+pub fn perspective_infinite<S: BaseFloat, A: Angle<S>>(fovy: A, aspect: S, near: S) -> Matrix4<S> {
+    let half_turn: A = Angle::turn_div_2();
+
+    assert!(fovy   > zero(),    "The vertical field of view cannot be below zero, found: {:?}", fovy);
+    assert!(fovy   < half_turn, "The vertical field of view cannot be greater than a half turn, found: {:?}", fovy);
+    assert!(aspect > zero(),    "The aspect ratio cannot be below zero, found: {:?}", aspect);
+    assert!(near   > zero(),    "The near plane distance cannot be below zero, found: {:?}", near);
+
+    let f = cot(fovy.div_s(cast(2i8).unwrap()).to_rad());
+    let two: S = cast(2i8).unwrap();
+
+    Matrix4::new(f / aspect, zero(),           zero(),  zero(),
+                     zero(),      f,           zero(),  zero(),
+                     zero(), zero(),     -one::<S>(), -one::<S>(),
+                     zero(), zero(),    -two * near,  zero())
+}
+
 /// Create a perspective matrix from a view frustrum.
 ///
 /// This is the equivalent of the now deprecated [glFrustrum]
@@ -64,6 +101,188 @@ pub fn ortho<S: BaseFloat + 'static>(left: S, right: S, bottom: S, top: S, near:
     }.to_matrix4()
 }
 
+/// Create an orthographic projection matrix centered on the view axis, with
+/// the given `width` and `height` of the view volume.
+///
+/// This is equivalent to `ortho(-width / 2, width / 2, -height / 2, height / 2,
+/// near, far)`.
+pub fn ortho_symmetric<S: BaseFloat + 'static>(width: S, height: S, near: S, far: S) -> Matrix4<S> {
+    let two: S = cast(2i8).unwrap();
+    let (x, y) = (width / two, height / two);
+    ortho(-x, x, -y, y, near, far)
+}
+
+/// Create a perspective projection matrix that maps depth to `[-1, 1]`, the
+/// OpenGL convention. This is the same as `perspective`, and is provided to
+/// make the depth range explicit alongside `perspective_zo`.
+#[inline]
+pub fn perspective_no<S: BaseFloat + 'static, A: Angle<S>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
+    perspective(fovy, aspect, near, far)
+}
+
+/// Create a perspective projection matrix that maps the near plane to a depth
+/// of `0` and the far plane to `1`, as used by Direct3D and Vulkan.
+///
+/// # Panics
+///
+/// If `fovy` is not between zero and a half turn, `aspect` is not positive,
+/// `near` is not positive or `far` is not beyond `near`.
+pub fn perspective_zo<S: BaseFloat, A: Angle<S>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
+    let half_turn: A = Angle::turn_div_2();
+
+    assert!(fovy   > zero(),    "The vertical field of view cannot be below zero, found: {:?}", fovy);
+    assert!(fovy   < half_turn, "The vertical field of view cannot be greater than a half turn, found: {:?}", fovy);
+    assert!(aspect > zero(),    "The aspect ratio cannot be below zero, found: {:?}", aspect);
+    assert!(near   > zero(),    "The near plane distance cannot be below zero, found: {:?}", near);
+    assert!(far    > near,      "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}", far, near);
+
+    let f = cot(fovy.div_s(cast(2i8).unwrap()).to_rad());
+
+    Matrix4::new(f / aspect, zero(),                      zero(),  zero(),
+                     zero(),      f,                      zero(),  zero(),
+                     zero(), zero(),          far / (near - far), -one::<S>(),
+                     zero(), zero(),  (far * near) / (near - far),  zero())
+}
+
+/// Create an orthographic projection matrix that maps depth to `[-1, 1]`.
+/// This is the same as `ortho`, and is provided to make the depth range
+/// explicit alongside `ortho_zo`.
+#[inline]
+pub fn ortho_no<S: BaseFloat + 'static>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    ortho(left, right, bottom, top, near, far)
+}
+
+/// Create an orthographic projection matrix that maps the near plane to a
+/// depth of `0` and the far plane to `1`, as used by Direct3D and Vulkan.
+pub fn ortho_zo<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    let two: S = cast(2i8).unwrap();
+
+    let c0r0 = two / (right - left);
+    let c1r1 = two / (top - bottom);
+    let c2r2 = -one::<S>() / (far - near);
+    let c3r0 = -(right + left) / (right - left);
+    let c3r1 = -(top + bottom) / (top - bottom);
+    let c3r2 = -near / (far - near);
+
+    Matrix4::new(c0r0, zero(), zero(), zero(),
+                 zero(), c1r1, zero(), zero(),
+                 zero(), zero(), c2r2, zero(),
+                 c3r0,   c3r1,   c3r2, one())
+}
+
+/// Create a perspective projection matrix with a reversed depth range, mapping
+/// the near plane to a depth of `1` and the far plane to `0`.
+///
+/// Unlike `perspective`, the resulting depth lies in `[0, 1]`, as used by
+/// Direct3D and Vulkan, or by OpenGL with `glClipControl(..., GL_ZERO_TO_ONE)`.
+/// Reversing the depth distributes floating point precision much more evenly
+/// over the view distance. It must be paired with a `GREATER` (or
+/// `GEQUAL`) depth test, and a depth buffer cleared to `0`.
+///
+/// # Panics
+///
+/// If `fovy` is not between zero and a half turn, `aspect` is not positive,
+/// `near` is not positive or `far` is not beyond `near`.
+pub fn perspective_reverse_z<S: BaseFloat, A: Angle<S>>(fovy: A, aspect: S, near: S, far: S) -> Matrix4<S> {
+    let half_turn: A = Angle::turn_div_2();
+
+    assert!(fovy   > zero(),    "The vertical field of view cannot be below zero, found: {:?}", fovy);
+    assert!(fovy   < half_turn, "The vertical field of view cannot be greater than a half turn, found: {:?}", fovy);
+    assert!(aspect > zero(),    "The aspect ratio cannot be below zero, found: {:?}", aspect);
+    assert!(near   > zero(),    "The near plane distance cannot be below zero, found: {:?}", near);
+    assert!(far    > near,      "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}", far, near);
+
+    let f = cot(fovy.div_s(cast(2i8).unwrap()).to_rad());
+
+    Matrix4::new(f / aspect, zero(),                      zero(),  zero(),
+                     zero(),      f,                      zero(),  zero(),
+                     zero(), zero(),         near / (far - near), -one::<S>(),
+                     zero(), zero(),  (far * near) / (far - near),  zero())
+}
+
+/// Create an orthographic projection matrix with a reversed depth range,
+/// mapping the near plane to a depth of `1` and the far plane to `0`.
+///
+/// As with `perspective_reverse_z`, the depth lies in `[0, 1]`, and the matrix
+/// must be paired with a `GREATER` (or `GEQUAL`) depth test.
+pub fn ortho_reverse_z<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
+    let two: S = cast(2i8).unwrap();
+
+    let c0r0 = two / (right - left);
+    let c1r1 = two / (top - bottom);
+    let c2r2 = one::<S>() / (far - near);
+    let c3r0 = -(right + left) / (right - left);
+    let c3r1 = -(top + bottom) / (top - bottom);
+    let c3r2 = far / (far - near);
+
+    Matrix4::new(c0r0, zero(), zero(), zero(),
+                 zero(), c1r1, zero(), zero(),
+                 zero(), zero(), c2r2, zero(),
+                 c3r0,   c3r1,   c3r2, one())
+}
+
+/// Map the object space point `obj` to window coordinates, given the
+/// `model`-view and `proj`ection matrices and the `(x, y, width, height)` of
+/// the viewport. The resulting depth lies in `[0, 1]` for points between the
+/// near and far planes, where `0` lies on the near plane.
+///
+/// This is the equivalent to the [gluProject]
+/// (http://www.opengl.org/sdk/docs/man2/xhtml/gluProject.xml) function.
+/// Returns `None` if the point projects to infinity, which happens for points
+/// in the plane of the camera.
+pub fn project<S: BaseFloat + 'static>(obj: &Point3<S>, model: &Matrix4<S>, proj: &Matrix4<S>,
+                                       viewport: (S, S, S, S)) -> Option<Point3<S>> {
+    let (x, y, width, height) = viewport;
+    let two: S = cast(2i8).unwrap();
+
+    proj.mul_m(model).mul_v(&obj.to_homogeneous()).homogenize().map(|ndc| {
+        Point3::new(x + width * (ndc.x + one::<S>()) / two,
+                    y + height * (ndc.y + one::<S>()) / two,
+                    (ndc.z + one::<S>()) / two)
+    })
+}
+
+/// Map the window coordinates `win` back to object space, given the
+/// `model`-view and `proj`ection matrices and the `(x, y, width, height)` of
+/// the viewport. The depth `win.z` is expected in `[0, 1]`, where `0` lies on
+/// the near plane and `1` on the far plane.
+///
+/// This is the equivalent to the [gluUnProject]
+/// (http://www.opengl.org/sdk/docs/man2/xhtml/gluUnProject.xml) function.
+/// Returns `None` if the combined matrix is not invertible, or if the window
+/// coordinates map to a point at infinity.
+pub fn unproject<S: BaseFloat + 'static>(win: &Point3<S>, model: &Matrix4<S>, proj: &Matrix4<S>,
+                                         viewport: (S, S, S, S)) -> Option<Point3<S>> {
+    let (x, y, width, height) = viewport;
+    let two: S = cast(2i8).unwrap();
+
+    let ndc = Vector4::new(two * (win.x - x) / width - one::<S>(),
+                           two * (win.y - y) / height - one::<S>(),
+                           two * win.z - one::<S>(),
+                           one());
+
+    proj.mul_m(model).invert()
+        .and_then(|m| m.mul_v(&ndc).homogenize())
+        .map(|v| Point3::from_vec(&v))
+}
+
+/// Create the ray in object space passing through the window position `win`,
+/// by unprojecting it onto the near and far planes. The ray starts on the
+/// near plane and its direction is normalized. See `unproject` for the
+/// meaning of the other arguments.
+///
+/// This is useful for picking objects with the mouse.
+pub fn unproject_ray<S: BaseFloat + 'static>(win: &Point2<S>, model: &Matrix4<S>, proj: &Matrix4<S>,
+                                             viewport: (S, S, S, S)) -> Option<Ray3<S>> {
+    let near = unproject(&Point3::new(win.x, win.y, zero()), model, proj, viewport);
+    let far = unproject(&Point3::new(win.x, win.y, one()), model, proj, viewport);
+
+    match (near, far) {
+        (Some(near), Some(far)) => Some(Ray::new(near, far.sub_p(&near).normalize())),
+        _ => None,
+    }
+}
+
 pub trait Projection<S>: ToMatrix4<S> {
     fn to_frustum(&self) -> Frustum<S>;
 }
@@ -13,8 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::default::Default;
 use std::fmt;
 use std::mem;
+use std::f32;
 use std::f64;
 use std::num::{cast, Float};
 use std::ops::*;
@@ -25,15 +27,19 @@ use angle::{Angle, Rad, acos, sin, sin_cos, rad};
 use approx::ApproxEq;
 use array::Array1;
 use matrix::{Matrix3, ToMatrix3, ToMatrix4, Matrix4};
-use num::{BaseFloat, one, zero};
+use num::{BaseFloat, Zero, one, zero};
 use point::Point3;
 use rotation::{Rotation, Rotation3, Basis3, ToBasis3};
 use vector::{Vector3, Vector, EuclideanVector};
 
 
 /// A [quaternion](https://en.wikipedia.org/wiki/Quaternion) in scalar/vector
-/// form.
+/// form. In memory, the scalar part is followed by the `x`, `y` and `z`
+/// components of the vector part.
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
 pub struct Quaternion<S> { pub s: S, pub v: Vector3<S> }
 
 /// Represents types which can be expressed as a quaternion.
@@ -116,6 +122,16 @@ impl<S: BaseFloat> Quaternion<S> {
         self.v.cross(&tmp).mul_s(cast(2i8).unwrap()).add_v(vec)
     }
 
+    /// Rotate a vector by this quaternion, which must be normalized. This
+    /// uses the formula `t = 2 * cross(q.v, v); v + q.s * t + cross(q.v, t)`,
+    /// which needs only two cross products and avoids converting the
+    /// quaternion to a matrix.
+    #[inline]
+    pub fn rotate_vector_fast(&self, vec: &Vector3<S>) -> Vector3<S> {
+        let t = self.v.cross(vec).mul_s(cast(2i8).unwrap());
+        vec.add_v(&t.mul_s(self.s)).add_v(&self.v.cross(&t))
+    }
+
     /// The sum of this quaternion and `other`
     #[inline]
     pub fn add_q(&self, other: &Quaternion<S>) -> Quaternion<S> {
@@ -167,8 +183,14 @@ impl<S: BaseFloat> Quaternion<S> {
     /// Multiply this quaternion by another, in-place.
     #[inline]
     pub fn mul_self_q(&mut self, q: &Quaternion<S>) {
-        self.s = self.s * q.s;
-        self.v.mul_self_v(&q.v);
+        *self = self.mul_q(q);
+    }
+
+    /// Negate this quaternion, in-place.
+    #[inline]
+    pub fn neg_self(&mut self) {
+        self.s = -self.s;
+        self.v.neg_self();
     }
 
     /// The dot product of the quaternion and `q`.
@@ -210,7 +232,13 @@ impl<S: BaseFloat> Quaternion<S> {
     }
 
     /// Do a normalized linear interpolation with `other`, by `amount`.
+    ///
+    /// Like `slerp`, this negates `other` if the dot product is negative so
+    /// that the interpolation follows the shortest arc. Unlike `slerp`, the
+    /// angular velocity is not constant, but no trigonometric functions need
+    /// to be evaluated.
     pub fn nlerp(&self, other: &Quaternion<S>, amount: S) -> Quaternion<S> {
+        let other = if self.dot(other) < zero::<S>() { -other.clone() } else { other.clone() };
         self.mul_s(one::<S>() - amount).add_q(&other.mul_s(amount)).normalize()
     }
 }
@@ -221,6 +249,34 @@ impl<S: BaseFloat> ApproxEq<S> for Quaternion<S> {
         self.s.approx_eq_eps(&other.s, epsilon) &&
         self.v.approx_eq_eps(&other.v, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Quaternion<S>, ulps: u32) -> bool {
+        self.s.approx_eq_ulps(&other.s, ulps) &&
+        self.v.approx_eq_ulps(&other.v, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Quaternion<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.s.relative_eq_eps(&other.s, epsilon, max_relative) &&
+        self.v.relative_eq_eps(&other.v, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> Quaternion<S> {
+    /// Returns `true` if every component of the quaternion is finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.s.is_finite() &&
+        self.v.is_finite()
+    }
+
+    /// Returns `true` if any component of the quaternion is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.s.is_nan() ||
+        self.v.is_nan()
+    }
 }
 
 impl<S: BaseFloat> Quaternion<S> {
@@ -229,6 +285,9 @@ impl<S: BaseFloat> Quaternion<S> {
     /// Return the spherical linear interpolation between the quaternion and
     /// `other`. Both quaternions should be normalized first.
     ///
+    /// If the dot product of the two quaternions is negative, `other` is
+    /// negated so that the interpolation always follows the shortest arc.
+    ///
     /// # Performance notes
     ///
     /// The `acos` operation used in `slerp` is an expensive operation, so
@@ -243,12 +302,20 @@ impl<S: BaseFloat> Quaternion<S> {
     pub fn slerp(&self, other: &Quaternion<S>, amount: S) -> Quaternion<S> {
         use std::num::cast;
 
-        let dot = self.dot(other);
+        let mut other = other.clone();
+        let mut dot = self.dot(&other);
         let dot_threshold = cast(0.9995f64).unwrap();
 
+        // `q` and `-q` represent the same rotation, so flip `other` to its
+        // antipode to take the shortest path
+        if dot < zero::<S>() {
+            other = -other;
+            dot = -dot;
+        }
+
         // if quaternions are close together use `nlerp`
         if dot > dot_threshold {
-            self.nlerp(other, amount)
+            self.nlerp(&other, amount)
         } else {
             // stay within the domain of acos()
             // TODO REMOVE WHEN https://github.com/mozilla/rust/issues/12068 IS RESOLVED
@@ -271,8 +338,13 @@ impl<S: BaseFloat> Quaternion<S> {
         }
     }
 
-    /// Convert a Quaternion to Eular angles
-    ///     This is a polar singularity aware conversion
+    /// Convert a quaternion to the euler angles `(x, y, z)` that reproduce it
+    /// when given to `Rotation3::from_euler`. Like there, the rotations are
+    /// applied intrinsically in the order `z`, `y`, `x`.
+    ///
+    /// This is a polar singularity aware conversion: when `y` is within a
+    /// small margin of `±π/2`, `x` is taken to be zero and the remaining
+    /// rotation is assigned to `z`.
     ///
     ///  Based on:
     /// - [Maths - Conversion Quaternion to Euler]
@@ -286,13 +358,13 @@ impl<S: BaseFloat> Quaternion<S> {
         let (sqw, sqx, sqy, sqz) = (qw*qw, qx*qx, qy*qy, qz*qz);
 
         let unit = sqx + sqy + sqz + sqw;
-        let test = qx*qy + qz*qw;
+        let test = qw*qy - qx*qz;
 
         if test > sig * unit {
             (
                 rad(zero::<S>()),
                 rad(cast(f64::consts::FRAC_PI_2).unwrap()),
-                rad(two * qx.atan2(qw)),
+                rad(-two * qx.atan2(qw)),
             )
         } else if test < -sig * unit {
             let y: S = cast(f64::consts::FRAC_PI_2).unwrap();
@@ -302,13 +374,158 @@ impl<S: BaseFloat> Quaternion<S> {
                 rad(two * qx.atan2(qw)),
             )
         } else {
+            // clamp to guard against rounding errors pushing the value
+            // outside the domain of `asin`
+            let sin_y = (two * test / unit).max(-one).min(one);
             (
-                rad((two * (qy*qw - qx*qz)).atan2(one - two*(sqy + sqz))),
-                rad((two * (qx*qy + qz*qw)).asin()),
-                rad((two * (qx*qw - qy*qz)).atan2(one - two*(sqx + sqz))),
+                rad((two * (qw*qx + qy*qz)).atan2(unit - two*(sqx + sqy))),
+                rad(sin_y.asin()),
+                rad((two * (qw*qz + qx*qy)).atan2(unit - two*(sqy + sqz))),
             )
         }
     }
+
+    /// Create the shortest rotation that turns the direction of `from` into
+    /// the direction of `to`. Neither vector needs to be normalized.
+    ///
+    /// If the vectors point in opposite directions, the rotation is a half
+    /// turn about an arbitrary axis perpendicular to `from`.
+    pub fn from_arc(from: &Vector3<S>, to: &Vector3<S>) -> Quaternion<S> {
+        let a = from.normalize();
+        let b = to.normalize();
+        let dot = a.dot(&b);
+        let cross = a.cross(&b);
+
+        //http://stackoverflow.com/questions/1171849/
+        //finding-quaternion-representing-the-rotation-from-one-vector-to-another
+        if dot >= zero() {
+            Quaternion::from_sv(one::<S>() + dot, cross).normalize()
+        } else if !cross.is_zero() {
+            // `1 + dot` cancels badly as the vectors approach opposite
+            // directions, so use the equivalent `|a × b|² / (1 - dot)`
+            Quaternion::from_sv(cross.length2() / (one::<S>() - dot), cross).normalize()
+        } else {
+            let mut axis = Vector3::unit_x().cross(&a);
+            if axis.is_zero() {
+                axis = Vector3::unit_y().cross(&a);
+            }
+            Quaternion::from_sv(zero(), axis.normalize())
+        }
+    }
+
+    /// Convert a quaternion to the axis and angle of the rotation it
+    /// represents. The quaternion is normalized first, so it need not have
+    /// unit length. The returned angle lies in the range `[0, 2π]`.
+    ///
+    /// The axis of the identity rotation is undefined, so when the vector part
+    /// is exactly zero the x-axis is returned, with an angle of zero (or `2π`
+    /// if the scalar part is negative). Any smaller rotation keeps its axis.
+    pub fn to_axis_angle(&self) -> (Vector3<S>, Rad<S>) {
+        let q = self.normalize();
+        let sin_half = q.v.length();
+
+        let two: S = cast(2f64).unwrap();
+        let angle = rad(two * sin_half.atan2(q.s));
+        if sin_half == zero() {
+            (Vector3::unit_x(), angle)
+        } else {
+            (q.v.div_s(sin_half), angle)
+        }
+    }
+
+    /// Advance the orientation by rotating with the angular velocity
+    /// `angular_velocity` (in radians per unit time, expressed in the outer
+    /// frame) for a timestep of `dt`, and renormalize the result.
+    ///
+    /// The step is applied as the exponential map of `ω·dt/2`. For very small
+    /// angles a Taylor expansion is used instead, so a zero angular velocity
+    /// is handled without dividing by a tiny magnitude.
+    pub fn integrate(&self, angular_velocity: &Vector3<S>, dt: S) -> Quaternion<S> {
+        let two: S = cast(2i8).unwrap();
+        let half = angular_velocity.mul_s(dt / two);
+        let theta2 = half.length2();
+        let epsilon: S = cast(f32::EPSILON).unwrap();
+
+        let (s, k) = if theta2 < epsilon * epsilon {
+            let six: S = cast(6i8).unwrap();
+            (one::<S>() - theta2 / two, one::<S>() - theta2 / six)
+        } else {
+            let theta = theta2.sqrt();
+            let (sin, cos) = theta.sin_cos();
+            (cos, sin / theta)
+        };
+
+        Quaternion::from_sv(s, half.mul_s(k)).mul_q(self).normalize()
+    }
+
+    /// The exponential of the quaternion, `e^s (cos|v| + v/|v| sin|v|)`.
+    pub fn exp(&self) -> Quaternion<S> {
+        let scale = self.s.exp();
+        let len = self.v.length();
+
+        if len == zero() {
+            Quaternion::from_sv(scale, zero())
+        } else {
+            let (sin, cos) = len.sin_cos();
+            Quaternion::from_sv(scale * cos, self.v.mul_s(scale * sin / len))
+        }
+    }
+
+    /// The natural logarithm of the quaternion. For a unit quaternion the
+    /// result is a pure quaternion whose vector part is half the rotation
+    /// angle times the rotation axis.
+    ///
+    /// When the vector part is zero, so that there is no well-defined axis,
+    /// the vector part of the result is zero.
+    pub fn ln(&self) -> Quaternion<S> {
+        let magnitude = self.magnitude();
+        let len = self.v.length();
+
+        if len == zero() {
+            Quaternion::from_sv(magnitude.ln(), zero())
+        } else {
+            Quaternion::from_sv(magnitude.ln(), self.v.mul_s(len.atan2(self.s) / len))
+        }
+    }
+
+    /// Raise the quaternion to the power `t`, computed as `exp(t ln q)`. For
+    /// a unit quaternion this scales the rotation angle by `t`.
+    pub fn powf(&self, t: S) -> Quaternion<S> {
+        self.ln().mul_s(t).exp()
+    }
+
+    /// Compute the intermediate control quaternion for the key `cur` of a
+    /// `squad` spline, given its neighbouring keys `prev` and `next`. All
+    /// three should be unit quaternions.
+    ///
+    /// The neighbours are negated if need be so that they lie in the same
+    /// hemisphere as `cur`.
+    pub fn squad_control(prev: &Quaternion<S>, cur: &Quaternion<S>, next: &Quaternion<S>) -> Quaternion<S> {
+        let prev = if cur.dot(prev) < zero() { -prev.clone() } else { prev.clone() };
+        let next = if cur.dot(next) < zero() { -next.clone() } else { next.clone() };
+        let inv = cur.conjugate();
+        let four: S = cast(4i8).unwrap();
+
+        let tangent = inv.mul_q(&next).ln().add_q(&inv.mul_q(&prev).ln());
+        cur.mul_q(&tangent.div_s(-four).exp())
+    }
+
+    /// Spherical quadrangle interpolation between the keys `q1` and `q2`,
+    /// using their neighbouring keys `q0` and `q3` to compute the control
+    /// quaternions. Interpolating each segment of a sequence of keys in this
+    /// way gives an orientation curve whose angular velocity is continuous at
+    /// the keys, unlike with `slerp`.
+    ///
+    /// At `t = 0` and `t = 1` the result is `q1` and `q2`, respectively. The
+    /// keys should be unit quaternions, each in the same hemisphere as the
+    /// one before it.
+    pub fn squad(q0: &Quaternion<S>, q1: &Quaternion<S>, q2: &Quaternion<S>, q3: &Quaternion<S>, t: S) -> Quaternion<S> {
+        let s1 = Quaternion::squad_control(q0, q1, q2);
+        let s2 = Quaternion::squad_control(q1, q2, q3);
+        let two: S = cast(2i8).unwrap();
+
+        q1.slerp(q2, t).slerp(&s1.slerp(&s2, t), two * t * (one::<S>() - t))
+    }
 }
 
 impl<S: BaseFloat> ToMatrix3<S> for Quaternion<S> {
@@ -362,6 +579,47 @@ impl<S: BaseFloat> ToMatrix4<S> for Quaternion<S> {
     }
 }
 
+impl<S: BaseFloat> Add for Quaternion<S> {
+    type Output = Quaternion<S>;
+
+    #[inline]
+    fn add(self, other: Quaternion<S>) -> Quaternion<S> { self.add_q(&other) }
+}
+
+impl<S: BaseFloat> Sub for Quaternion<S> {
+    type Output = Quaternion<S>;
+
+    #[inline]
+    fn sub(self, other: Quaternion<S>) -> Quaternion<S> { self.sub_q(&other) }
+}
+
+impl<S: BaseFloat> Mul for Quaternion<S> {
+    type Output = Quaternion<S>;
+
+    #[inline]
+    fn mul(self, other: Quaternion<S>) -> Quaternion<S> { self.mul_q(&other) }
+}
+
+impl<S: BaseFloat> Mul<S> for Quaternion<S> {
+    type Output = Quaternion<S>;
+
+    #[inline]
+    fn mul(self, s: S) -> Quaternion<S> { self.mul_s(s) }
+}
+
+impl<S: BaseFloat> Mul<Vector3<S>> for Quaternion<S> {
+    type Output = Vector3<S>;
+
+    #[inline]
+    fn mul(self, v: Vector3<S>) -> Vector3<S> { self.mul_v(&v) }
+}
+
+/// The default quaternion is the identity rotation.
+impl<S: BaseFloat> Default for Quaternion<S> {
+    #[inline]
+    fn default() -> Quaternion<S> { Quaternion::identity() }
+}
+
 impl<S: BaseFloat> Neg for Quaternion<S> {
     type Output = Quaternion<S>;
 
@@ -397,16 +655,50 @@ impl<S: BaseFloat + 'static> Rotation<S, Vector3<S>, Point3<S>> for Quaternion<S
     #[inline]
     fn identity() -> Quaternion<S> { Quaternion::identity() }
 
-    #[inline]
+    /// Create a rotation that turns `dir` onto the `z` axis, using `up` for
+    /// orientation, without going through an intermediate matrix. If `dir`
+    /// is parallel to `up`, another axis is used in place of `up`, as in
+    /// `Matrix3::look_at`.
     fn look_at(dir: &Vector3<S>, up: &Vector3<S>) -> Quaternion<S> {
-        Matrix3::look_at(dir, up).to_quaternion()
+        let half: S = cast(0.5f64).unwrap();
+
+        let d = dir.normalize();
+        let mut side = up.cross(&d);
+        if side.length2().approx_eq(&zero()) {
+            let alt = if d.x.abs() < cast(0.9f64).unwrap() { Vector3::unit_x() }
+                      else { Vector3::unit_y() };
+            side = alt.cross(&d);
+        }
+        let s = side.normalize();
+        let u = d.cross(&s);
+
+        // `s`, `u` and `d` are the rows of the rotation matrix, so convert it
+        // to a quaternion, branching on the largest of the trace and the
+        // diagonal elements so that the square root is never taken of a
+        // value near zero (Shepperd's method)
+        let trace = s.x + u.y + d.z;
+        if trace >= s.x && trace >= u.y && trace >= d.z {
+            let r = (one::<S>() + trace).sqrt();
+            let h = half / r;
+            Quaternion::new(half * r, (d.y - u.z) * h, (s.z - d.x) * h, (u.x - s.y) * h)
+        } else if s.x >= u.y && s.x >= d.z {
+            let r = (one::<S>() + s.x - u.y - d.z).sqrt();
+            let h = half / r;
+            Quaternion::new((d.y - u.z) * h, half * r, (s.y + u.x) * h, (s.z + d.x) * h)
+        } else if u.y >= d.z {
+            let r = (one::<S>() + u.y - s.x - d.z).sqrt();
+            let h = half / r;
+            Quaternion::new((s.z - d.x) * h, (s.y + u.x) * h, half * r, (u.z + d.y) * h)
+        } else {
+            let r = (one::<S>() + d.z - s.x - u.y).sqrt();
+            let h = half / r;
+            Quaternion::new((u.x - s.y) * h, (s.z + d.x) * h, (u.z + d.y) * h, half * r)
+        }
     }
 
     #[inline]
     fn between_vectors(a: &Vector3<S>, b: &Vector3<S>) -> Quaternion<S> {
-        //http://stackoverflow.com/questions/1171849/
-        //finding-quaternion-representing-the-rotation-from-one-vector-to-another
-        Quaternion::from_sv(one::<S>() + a.dot(b), a.cross(b)).normalize()
+        Quaternion::from_arc(a, b)
     }
 
     #[inline]
@@ -435,14 +727,14 @@ impl<S: BaseFloat> Rotation3<S> for Quaternion<S> where S: 'static {
     /// - [Maths - Conversion Euler to Quaternion]
     ///   (http://www.euclideanspace.com/maths/geometry/rotations/conversions/eulerToQuaternion/index.htm)
     fn from_euler(x: Rad<S>, y: Rad<S>, z: Rad<S>) -> Quaternion<S> {
-        let (s1, c1) = sin_cos(x.mul_s(cast(0.5f64).unwrap()));
-        let (s2, c2) = sin_cos(y.mul_s(cast(0.5f64).unwrap()));
-        let (s3, c3) = sin_cos(z.mul_s(cast(0.5f64).unwrap()));
-
-        Quaternion::new(c1 * c2 * c3 - s1 * s2 * s3,
-                        s1 * s2 * c3 + c1 * c2 * s3,
-                        s1 * c2 * c3 + c1 * s2 * s3,
-                        c1 * s2 * c3 - s1 * c2 * s3)
+        let (sx, cx) = sin_cos(x.mul_s(cast(0.5f64).unwrap()));
+        let (sy, cy) = sin_cos(y.mul_s(cast(0.5f64).unwrap()));
+        let (sz, cz) = sin_cos(z.mul_s(cast(0.5f64).unwrap()));
+
+        Quaternion::new(cx * cy * cz + sx * sy * sz,
+                        sx * cy * cz - cx * sy * sz,
+                        cx * sy * cz + sx * cy * sz,
+                        cx * cy * sz - sx * sy * cz)
     }
 }
 
@@ -13,9 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use num::BaseNum;
+use num::{BaseNum, BaseFloat};
 use point::{Point, Point2, Point3};
-use vector::{Vector, Vector2, Vector3};
+use vector::{Vector, EuclideanVector, Vector2, Vector3};
 
 /// A generic ray starting at `origin` and extending infinitely in
 /// `direction`.
@@ -30,6 +30,22 @@ impl<S: BaseNum, V: Vector<S>, P: Point<S, V>> Ray<P, V> {
     pub fn new(origin: P, direction: V) -> Ray<P,V> {
         Ray { origin: origin, direction: direction }
     }
+
+    /// Return the point at the parametric distance `t` along the ray, that is,
+    /// `origin + direction * t`.
+    #[inline]
+    pub fn point_at(&self, t: S) -> P {
+        self.origin.add_v(&self.direction.mul_s(t))
+    }
+}
+
+#[old_impl_check]
+impl<S: BaseFloat, V: EuclideanVector<S>, P: Point<S, V>> Ray<P, V> {
+    /// Create a new ray, normalizing `direction` so that `t` in `point_at` is
+    /// the distance from the origin.
+    pub fn new_normalized(origin: P, direction: V) -> Ray<P,V> {
+        Ray::new(origin, direction.normalize())
+    }
 }
 
 pub type Ray2<S> = Ray<Point2<S>, Vector2<S>>;
@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use angle::{Rad, acos};
+use angle::{Angle, Rad, acos, atan2};
 use approx::ApproxEq;
 use matrix::Matrix;
 use matrix::{Matrix2, ToMatrix2};
@@ -30,7 +30,8 @@ pub trait Rotation<S: BaseNum, V: Vector<S>, P: Point<S, V>>: PartialEq + Approx
     /// Create the identity transform (causes no transformation).
     fn identity() -> Self;
 
-    /// Create a rotation to a given direction with an 'up' vector
+    /// Create a rotation to a given direction with an 'up' vector. For the
+    /// 3D rotations, the result maps `dir` onto the positive `z` axis.
     fn look_at(dir: &V, up: &V) -> Self;
 
     /// Create a shortest rotation to transform vector 'a' into 'b'.
@@ -47,6 +48,12 @@ pub trait Rotation<S: BaseNum, V: Vector<S>, P: Point<S, V>>: PartialEq + Approx
         Point::from_vec( &self.rotate_vector( &point.to_vec() ) )
     }
 
+    /// Rotate a point about `pivot` rather than the origin.
+    #[inline]
+    fn rotate_around(&self, point: &P, pivot: &P) -> P {
+        pivot.add_v(&self.rotate_vector(&point.sub_p(pivot)))
+    }
+
     /// Rotate a ray using this rotation.
     #[inline]
     fn rotate_ray(&self, ray: &Ray<P, V>) -> Ray<P,V> {
@@ -87,10 +94,13 @@ pub trait Rotation3<S: BaseNum>: Rotation<S, Vector3<S>, Point3<S>>
                                + ToMatrix3<S>
                                + ToBasis3<S>
                                + ToQuaternion<S>{
-    /// Create a rotation using an angle around a given axis.
+    /// Create a rotation using an angle around a given axis. The axis must be
+    /// of unit length.
     fn from_axis_angle(axis: &Vector3<S>, angle: Rad<S>) -> Self;
 
-    /// Create a rotation from a set of euler angles.
+    /// Create a rotation from a set of euler angles. The rotations are
+    /// applied intrinsically in the order `z`, `y`, `x`, which is the same as
+    /// rotating about the fixed `x`, `y` and `z` axes in turn.
     ///
     /// # Parameters
     ///
@@ -170,6 +180,15 @@ impl<S: BaseFloat> Basis2<S> {
     /// Coerce to a `Matrix2`
     #[inline]
     pub fn as_matrix2<'a>(&'a self) -> &'a Matrix2<S> { &self.mat }
+
+    /// Extract the angle of the rotation, in the range `(-π, π]`. A
+    /// `Basis2` can only be constructed from proper rotations, so there is
+    /// no reflection to account for.
+    pub fn to_angle(&self) -> Rad<S> {
+        let angle = atan2(self.mat.x.y, self.mat.x.x);
+        let half_turn: Rad<S> = Angle::turn_div_2();
+        if angle <= -half_turn { half_turn } else { angle }
+    }
 }
 
 /// Represents types which can be converted to a rotation matrix.
@@ -227,6 +246,16 @@ impl<S: BaseFloat> ApproxEq<S> for Basis2<S> {
     fn approx_eq_eps(&self, other: &Basis2<S>, epsilon: &S) -> bool {
         self.mat.approx_eq_eps(&other.mat, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Basis2<S>, ulps: u32) -> bool {
+        self.mat.approx_eq_ulps(&other.mat, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Basis2<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.mat.relative_eq_eps(&other.mat, epsilon, max_relative)
+    }
 }
 
 impl<S: BaseFloat + 'static> Rotation2<S> for Basis2<S> {
@@ -317,6 +346,16 @@ impl<S: BaseFloat> ApproxEq<S> for Basis3<S> {
     fn approx_eq_eps(&self, other: &Basis3<S>, epsilon: &S) -> bool {
         self.mat.approx_eq_eps(&other.mat, epsilon)
     }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Basis3<S>, ulps: u32) -> bool {
+        self.mat.approx_eq_ulps(&other.mat, ulps)
+    }
+
+    #[inline]
+    fn relative_eq_eps(&self, other: &Basis3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.mat.relative_eq_eps(&other.mat, epsilon, max_relative)
+    }
 }
 
 impl<S: BaseFloat + 'static> Rotation3<S> for Basis3<S> {
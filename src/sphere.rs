@@ -15,13 +15,16 @@
 
 //! Bounding sphere
 
+use std::fmt;
+use std::num::cast;
+
 use bound::*;
 use intersect::Intersect;
 use num::{BaseFloat, zero};
 use point::{Point, Point3};
 use plane::Plane;
 use ray::Ray3;
-use vector::Vector;
+use vector::{Vector, EuclideanVector};
 
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
 pub struct Sphere<S> {
@@ -29,17 +32,96 @@ pub struct Sphere<S> {
     pub radius: S,
 }
 
+impl<S: BaseFloat> Sphere<S> {
+    /// Construct a new sphere from its center and radius.
+    #[inline]
+    pub fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere { center: center, radius: radius }
+    }
+
+    /// Construct a sphere that encloses all of the given points, using
+    /// Ritter's algorithm. The result is not necessarily the smallest such
+    /// sphere, but is usually within a few percent of it. Returns `None` if
+    /// the slice is empty.
+    pub fn from_points(points: &[Point3<S>]) -> Option<Sphere<S>> {
+        if points.is_empty() { return None; }
+
+        let farthest_from = |p: &Point3<S>| {
+            let mut best = points[0];
+            for q in points.iter() {
+                if q.sub_p(p).length2() > best.sub_p(p).length2() { best = *q; }
+            }
+            best
+        };
+
+        // start with a sphere spanning two points that are far apart
+        let x = farthest_from(&points[0]);
+        let y = farthest_from(&x);
+        let two = cast(2.0f64).unwrap();
+        let mut sphere = Sphere::new(x.add_v(&y.sub_p(&x).div_s(two)),
+                                     y.sub_p(&x).length() / two);
+
+        // then grow it to include any points that were missed
+        for p in points.iter() {
+            let v = p.sub_p(&sphere.center);
+            let dist = v.length();
+            if dist > sphere.radius {
+                let radius = (sphere.radius + dist) / two;
+                sphere.center = sphere.center.add_v(&v.mul_s((radius - sphere.radius) / dist));
+                sphere.radius = radius;
+            }
+        }
+
+        Some(sphere)
+    }
+
+    /// Tests whether a point is contained in the sphere, inclusive of its
+    /// surface.
+    #[inline]
+    pub fn contains(&self, p: &Point3<S>) -> bool {
+        p.sub_p(&self.center).length2() <= self.radius * self.radius
+    }
+
+    /// Tests whether this sphere overlaps `other`, including if they only
+    /// touch.
+    #[inline]
+    pub fn intersects(&self, other: &Sphere<S>) -> bool {
+        let r = self.radius + other.radius;
+        self.center.sub_p(&other.center).length2() <= r * r
+    }
+
+    /// Find the parametric distance `t` along `ray` at which it first hits
+    /// the surface of the sphere. If the ray starts inside the sphere, this
+    /// is the point at which it leaves. Returns `None` if the ray misses the
+    /// sphere or points away from it.
+    pub fn intersects_ray(&self, ray: &Ray3<S>) -> Option<S> {
+        let l = ray.origin.sub_p(&self.center);
+        let a = ray.direction.length2();
+        let b = l.dot(&ray.direction);
+        let c = l.length2() - self.radius * self.radius;
+
+        let discr = b * b - a * c;
+        if discr < zero() { return None; }
+
+        let sq = discr.sqrt();
+        let t0 = (-b - sq) / a;
+        let t1 = (-b + sq) / a;
+        if t0 >= zero() { Some(t0) }
+        else if t1 >= zero() { Some(t1) }
+        else { None }
+    }
+}
+
 impl<S: BaseFloat> Intersect<Option<Point3<S>>> for (Sphere<S>, Ray3<S>) {
     fn intersection(&self) -> Option<Point3<S>> {
         let (ref s, ref r) = *self;
+        s.intersects_ray(r).map(|t| r.origin.add_v(&r.direction.mul_s(t)))
+    }
+}
 
-        let l = s.center.sub_p(&r.origin);
-        let tca = l.dot(&r.direction);
-        if tca < zero() { return None; }
-        let d2 = l.dot(&l) - tca*tca;
-        if d2 > s.radius*s.radius { return None; }
-        let thc = (s.radius*s.radius - d2).sqrt();
-        Some(r.origin.add_v(&r.direction.mul_s(tca - thc)))
+impl<S: BaseFloat> fmt::Debug for Sphere<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:?}, {:?}]", self.center, self.radius)
     }
 }
 
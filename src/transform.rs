@@ -15,6 +15,7 @@
 
 use std::fmt;
 
+use aabb::Aabb3;
 use approx::ApproxEq;
 use matrix::*;
 use num::*;
@@ -78,10 +79,14 @@ pub trait Transform<S: BaseNum, V: Vector<S>, P: Point<S, V>>: Sized + PhantomFn
 /// A generic transformation consisting of a rotation,
 /// displacement vector and scale amount.
 #[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
-pub struct Decomposed<S, V, R> {
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+// The displacement type is not called `V`, as that clashes with the type
+// parameters generated by `derive_serialize`.
+pub struct Decomposed<S, D, R> {
     pub scale: S,
     pub rot: R,
-    pub disp: V,
+    pub disp: D,
 }
 
 impl<
@@ -99,10 +104,15 @@ impl<
         }
     }
 
+    /// Create a view transform equivalent to `Matrix4::look_at`, which moves
+    /// `eye` to the origin and turns the direction towards `center` onto the
+    /// negative `z` axis.
     #[inline]
     fn look_at(eye: &P, center: &P, up: &V) -> Decomposed<S, V, R> {
         let origin: P = Point::origin();
-        let rot: R = Rotation::look_at(&center.sub_p(eye), up);
+        // `Rotation::look_at` turns its direction onto the positive `z` axis,
+        // so look away from `center` to end up facing down the negative axis
+        let rot: R = Rotation::look_at(&eye.sub_p(center), up);
         let disp: V = rot.rotate_vector(&origin.sub_p(eye));
         Decomposed {
             scale: one(),
@@ -121,6 +131,8 @@ impl<
         self.rot.rotate_point(&point.mul_s(self.scale.clone())).add_v(&self.disp)
     }
 
+    /// The combined displacement is `self.disp + self.rot * (other.disp *
+    /// self.scale)`, that is, `other`'s displacement as seen through `self`.
     fn concat(&self, other: &Decomposed<S, V, R>) -> Decomposed<S, V, R> {
         Decomposed {
             scale: self.scale * other.scale,
@@ -129,6 +141,14 @@ impl<
         }
     }
 
+    #[inline]
+    fn concat_self(&mut self, other: &Decomposed<S, V, R>) {
+        // The displacement must be computed with the old scale and rotation
+        self.disp = self.transform_as_point(&other.disp);
+        self.scale = self.scale * other.scale;
+        self.rot.concat_self(&other.rot);
+    }
+
     fn invert(&self) -> Option<Decomposed<S, V, R>> {
         if self.scale.approx_eq(&zero()) {
             None
@@ -146,7 +166,29 @@ impl<
 }
 
 pub trait Transform2<S>: Transform<S, Vector2<S>, Point2<S>> + ToMatrix3<S> {}
-pub trait Transform3<S>: Transform<S, Vector3<S>, Point3<S>> + ToMatrix4<S> {}
+pub trait Transform3<S>: Transform<S, Vector3<S>, Point3<S>> + ToMatrix4<S> {
+    /// Transform an axis-aligned bounding box, returning the smallest
+    /// axis-aligned box that encloses the transformed one.
+    ///
+    /// This uses Arvo's method, which starts from the translation and
+    /// accumulates the contribution of each input axis to each output axis,
+    /// rather than transforming and re-bounding all eight corners. The
+    /// transform is assumed to be affine.
+    fn transform_bound(&self, aabb: &Aabb3<S>) -> Aabb3<S> where S: BaseNum {
+        let m = self.to_matrix4();
+        let mut min = Point3::from_vec(&m.w.truncate());
+        let mut max = min;
+        for i in 0..3 {
+            for j in 0..3 {
+                let a = m[j][i] * aabb.min[j];
+                let b = m[j][i] * aabb.max[j];
+                min[i] = min[i] + a.partial_min(b);
+                max[i] = max[i] + a.partial_max(b);
+            }
+        }
+        Aabb3 { min: min, max: max }
+    }
+}
 
 impl<
     S: BaseFloat + 'static,
@@ -190,6 +232,122 @@ impl<
     }
 }
 
+/// A transformation consisting of a rotation, displacement vector and a
+/// separate scale factor along each axis.
+///
+/// Unlike `Decomposed`, this does not implement `Transform`, because the
+/// combination or inverse of two such transforms cannot, in general, be
+/// expressed as a single scale followed by a rotation. Instead, `concat` and
+/// `invert` return `None` in those cases, and the transforms should be
+/// combined as matrices using `to_matrix4`.
+#[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
+pub struct NonUniformDecomposed<S, R> {
+    pub scale: Vector3<S>,
+    pub rot: R,
+    pub disp: Vector3<S>,
+}
+
+impl<S: BaseFloat + 'static, R: Rotation3<S>> NonUniformDecomposed<S, R> {
+    /// Create an identity transformation.
+    #[inline]
+    pub fn identity() -> NonUniformDecomposed<S, R> {
+        NonUniformDecomposed {
+            scale: Vector3::from_value(one()),
+            rot: Rotation::identity(),
+            disp: zero(),
+        }
+    }
+
+    /// Transform a vector using this transform.
+    #[inline]
+    pub fn transform_vector(&self, vec: &Vector3<S>) -> Vector3<S> {
+        self.rot.rotate_vector(&vec.mul_v(&self.scale))
+    }
+
+    /// Transform a point using this transform.
+    #[inline]
+    pub fn transform_point(&self, point: &Point3<S>) -> Point3<S> {
+        Point::from_vec(&self.transform_vector(&point.to_vec()).add_v(&self.disp))
+    }
+
+    /// Returns `true` if the scale is the same along every axis.
+    #[inline]
+    pub fn is_uniform(&self) -> bool {
+        self.scale.x == self.scale.y && self.scale.x == self.scale.z
+    }
+
+    /// Combine this transform with another, yielding a new transformation
+    /// which has the effects of both.
+    ///
+    /// This is only possible if this transform has a uniform scale, or if
+    /// `other` does not rotate. Otherwise, `None` is returned.
+    pub fn concat(&self, other: &NonUniformDecomposed<S, R>) -> Option<NonUniformDecomposed<S, R>> {
+        if !self.is_uniform() && !is_identity_rotation(&other.rot) { return None; }
+
+        Some(NonUniformDecomposed {
+            scale: self.scale.mul_v(&other.scale),
+            rot: self.rot.concat(&other.rot),
+            disp: self.transform_point(&Point::from_vec(&other.disp)).to_vec(),
+        })
+    }
+
+    /// Create a transform that "un-does" this one.
+    ///
+    /// Returns `None` if the scale is zero along any axis, or if the scale is
+    /// not uniform and the transform also rotates, as the inverse would then
+    /// need to rotate before scaling.
+    pub fn invert(&self) -> Option<NonUniformDecomposed<S, R>> {
+        if self.scale.x == zero() || self.scale.y == zero() || self.scale.z == zero() {
+            return None;
+        }
+
+        if !self.is_uniform() && !is_identity_rotation(&self.rot) { return None; }
+
+        let scale = Vector3::from_value(one::<S>()).div_v(&self.scale);
+        let rot = self.rot.invert();
+        let disp = -rot.rotate_vector(&self.disp).mul_v(&scale);
+        Some(NonUniformDecomposed {
+            scale: scale,
+            rot: rot,
+            disp: disp,
+        })
+    }
+}
+
+/// Returns `true` if `rot` leaves every vector unchanged.
+fn is_identity_rotation<S: BaseFloat + 'static, R: Rotation3<S>>(rot: &R) -> bool {
+    rot.rotate_vector(&Vector3::unit_x()) == Vector3::unit_x() &&
+    rot.rotate_vector(&Vector3::unit_y()) == Vector3::unit_y()
+}
+
+impl<S: BaseFloat + 'static, R: Rotation3<S>> ToMatrix4<S> for NonUniformDecomposed<S, R> {
+    fn to_matrix4(&self) -> Matrix4<S> {
+        let mut m = self.rot.to_matrix3().mul_m(&Matrix3::from_diagonal(&self.scale)).to_matrix4();
+        m.w = self.disp.extend(one());
+        m
+    }
+}
+
+impl<S: BaseFloat + 'static, R: Rotation3<S> + Clone>
+ToComponents<S, Vector3<S>, Point3<S>, R> for NonUniformDecomposed<S, R> {
+    fn decompose(&self) -> (Vector3<S>, R, Vector3<S>) {
+        (self.scale.clone(), self.rot.clone(), self.disp.clone())
+    }
+}
+
+impl<S: BaseFloat + 'static, R: Rotation3<S> + Clone>
+ToComponents3<S, R> for NonUniformDecomposed<S, R> {}
+
+impl<
+    S: BaseFloat,
+    R: fmt::Debug + Rotation3<S>,
+> fmt::Debug for NonUniformDecomposed<S, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(scale{:?}, rot({:?}), disp{:?})",
+            self.scale, self.rot, self.disp)
+    }
+}
+
 /// A homogeneous transformation matrix.
 #[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
 pub struct AffineMatrix3<S> {
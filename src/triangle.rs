@@ -0,0 +1,60 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Triangle utilities
+
+use approx::ApproxEq;
+use num::{BaseFloat, one, zero};
+use point::Point;
+use vector::Vector;
+
+/// The area of the triangle with vertices `a`, `b` and `c`. This works for
+/// both 2D and 3D points. A degenerate triangle has zero area.
+pub fn triangle_area<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(a: &P, b: &P, c: &P) -> S {
+    let u = b.sub_p(a);
+    let v = c.sub_p(a);
+    // Lagrange's identity gives |u x v|^2 without needing a cross product
+    let cross2 = u.dot(&u) * v.dot(&v) - u.dot(&v) * u.dot(&v);
+    let half: S = one::<S>() / (one::<S>() + one::<S>());
+    cross2.max(zero()).sqrt() * half
+}
+
+/// The barycentric coordinates `(u, v, w)` of `p` with respect to the
+/// triangle `a`, `b`, `c`, such that `p = u * a + v * b + w * c` and
+/// `u + v + w = 1`. For 3D points, `p` is first projected onto the plane of
+/// the triangle.
+///
+/// Returns `None` if the triangle is degenerate, having collinear or
+/// coincident vertices.
+pub fn barycentric<S: BaseFloat, V: Vector<S>, P: Point<S, V>>(p: &P, a: &P, b: &P, c: &P) -> Option<(S, S, S)> {
+    let v0 = b.sub_p(a);
+    let v1 = c.sub_p(a);
+    let v2 = p.sub_p(a);
+
+    let d00 = v0.dot(&v0);
+    let d01 = v0.dot(&v1);
+    let d11 = v1.dot(&v1);
+    let d20 = v2.dot(&v0);
+    let d21 = v2.dot(&v1);
+
+    let denom = d00 * d11 - d01 * d01;
+    if denom.approx_eq(&zero()) {
+        return None;
+    }
+
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Some((one::<S>() - v - w, v, w))
+}
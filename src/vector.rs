@@ -30,9 +30,10 @@
 //! ```
 //!
 //! Vectors can be manipulated with typical mathematical operations (addition,
-//! subtraction, element-wise multiplication, element-wise division, negation)
-//! using the built-in operators. The additive and multiplicative neutral
-//! elements (zero and one) are also provided by this library
+//! subtraction, element-wise multiplication, element-wise division, negation,
+//! and multiplication or division by a scalar) using the built-in operators.
+//! The additive and multiplicative neutral elements (zero and one) are also
+//! provided by this library
 //!
 //! ```rust
 //! use cgmath::{Vector2, Vector3, Vector4, one, zero};
@@ -42,7 +43,8 @@
 //!
 //! assert_eq!(a + b, zero());
 //! assert_eq!(-(a * b), Vector2::new(9.0f64, 16.0f64));
-//! assert_eq!(a / one(), a);
+//! assert_eq!(a / one::<Vector2<f64>>(), a);
+//! assert_eq!(a * 2.0, Vector2::new(6.0f64, 8.0f64));
 //!
 //! // As with Rust's `int` and `f32` types, Vectors of different types cannot
 //! // be added and so on with impunity. The following will fail to compile:
@@ -96,14 +98,18 @@
 //! features of the vectors, while [`EuclideanVector`]
 //! (../array/trait.EuclideanVector.html) is more specific to Euclidean space.
 
+use std::default::Default;
 use std::fmt;
+use std::iter::{FromIterator, IntoIterator};
 use std::mem;
-use std::num::NumCast;
+use std::ptr;
+use std::slice;
+use std::num::{NumCast, cast};
 use std::ops::*;
 
 use rand::{Rand, Rng};
 
-use angle::{Rad, atan2, acos};
+use angle::{Angle, Rad, atan2};
 use approx::ApproxEq;
 use array::{Array1, FixedArray};
 use num::{BaseNum, BaseFloat, Zero, One, zero, one};
@@ -184,23 +190,102 @@ pub trait Vector<S: BaseNum>: Array1<S> + Zero + One + Neg<Output=Self> {
     fn comp_min(&self) -> S;
     /// The maximum component of the vector.
     fn comp_max(&self) -> S;
+
+    /// The Manhattan (L1) norm of the vector, the sum of the absolute values
+    /// of its components.
+    fn l1_norm(&self) -> S;
+    /// The Chebyshev (L-infinity) norm of the vector, the largest absolute
+    /// value of its components.
+    fn linf_norm(&self) -> S;
+
+    /// The component-wise minimum of this vector and `v`. For floating point
+    /// vectors, a `NaN` component in one operand is ignored in favour of the
+    /// corresponding component of the other, following `Float::min`.
+    #[must_use]
+    fn min(&self, v: &Self) -> Self;
+    /// The component-wise maximum of this vector and `v`. `NaN` components are
+    /// handled in the same way as in `min`.
+    #[must_use]
+    fn max(&self, v: &Self) -> Self;
+
+    /// Clamp each component of the vector to the range given by the
+    /// corresponding components of `lo` and `hi`.
+    #[must_use]
+    fn clamp(&self, lo: &Self, hi: &Self) -> Self;
 }
 
 /// Dot product of two vectors.
 #[inline] pub fn dot<S: BaseNum, V: Vector<S>>(a: V, b: V) -> S { a.dot(&b) }
 
+/// Sum the vectors produced by an iterator. An empty iterator gives the zero
+/// vector.
+#[inline]
+pub fn sum<S: BaseNum, V: Vector<S>, I: Iterator<Item=V>>(iter: I) -> V {
+    iter.fold(zero(), |acc, v| acc.add_v(&v))
+}
+
+/// Multiply together the vectors produced by an iterator, component-wise. An
+/// empty iterator gives the vector with every component set to one.
+#[inline]
+pub fn product<S: BaseNum, V: Vector<S>, I: Iterator<Item=V>>(iter: I) -> V {
+    iter.fold(one(), |acc, v| acc.mul_v(&v))
+}
+
 // Utility macro for generating associated functions for the vectors
 macro_rules! vec(
     ($Self_:ident <$S:ident> { $($field:ident),+ }, $n:expr, $constructor:ident) => (
-        #[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
-        pub struct $Self_<S> { $(pub $field: S),+ }
-
         impl<$S> $Self_<$S> {
             /// Construct a new vector, using the provided values.
             #[inline]
             pub fn new($($field: $S),+) -> $Self_<$S> {
                 $Self_ { $($field: $field),+ }
             }
+
+            /// View the components of the vector as a slice, in the order in
+            /// which they are declared. The vector types are `#[repr(C)]`, so
+            /// this is a view of the vector's own memory.
+            #[inline]
+            pub fn as_slice<'a>(&'a self) -> &'a [$S] {
+                &self.as_fixed()[..]
+            }
+
+            /// View the components of the vector as a mutable slice.
+            #[inline]
+            pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [$S] {
+                &mut self.as_mut_fixed()[..]
+            }
+
+            /// Iterate over the components of the vector, in the order in
+            /// which they are declared.
+            #[inline]
+            pub fn iter<'a>(&'a self) -> slice::Iter<'a, $S> {
+                self.as_slice().iter()
+            }
+
+            /// Iterate mutably over the components of the vector.
+            #[inline]
+            pub fn iter_mut<'a>(&'a mut self) -> slice::IterMut<'a, $S> {
+                self.as_mut_slice().iter_mut()
+            }
+        }
+
+        impl<$S: Copy> $Self_<$S> {
+            /// Apply a function to each component, returning a new vector of
+            /// the results. Unlike `Array1::map`, this leaves the vector
+            /// unchanged and the result may have a different component type.
+            #[inline]
+            pub fn map_to<T, F>(&self, mut f: F) -> $Self_<T> where F: FnMut($S) -> T {
+                $Self_ { $($field: f(self.$field)),+ }
+            }
+
+            /// Combine the components of this vector with the corresponding
+            /// components of `other` using a function, returning a new vector
+            /// of the results.
+            #[inline]
+            pub fn zip_map<T: Copy, U, F>(&self, other: &$Self_<T>, mut f: F) -> $Self_<U>
+                    where F: FnMut($S, T) -> U {
+                $Self_ { $($field: f(self.$field, other.$field)),+ }
+            }
         }
 
         /// The short constructor.
@@ -217,16 +302,31 @@ macro_rules! vec(
             fn is_zero(&self) -> bool { $((self.$field.is_zero()) )&&+ }
         }
 
+        /// The default vector is the zero vector.
+        impl<$S: Zero> Default for $Self_<$S> {
+            #[inline]
+            fn default() -> $Self_<$S> { zero() }
+        }
+
         impl<$S: One> One for $Self_<$S> {
             #[inline]
             fn one() -> $Self_<$S> { $Self_ { $($field: one()),+ } }
         }
 
         impl<$S: NumCast + Copy> $Self_<$S> {
-            /// Component-wise casting to another type
+            /// Component-wise casting to another type. Returns `None` if any
+            /// component can not be represented in the new type, as reported by
+            /// `NumCast`. For example, casting a negative integer to an
+            /// unsigned type fails.
             #[inline]
-            pub fn cast<T: NumCast>(&self) -> $Self_<T> {
-                $Self_ { $($field: NumCast::from(self.$field).unwrap()),+ }
+            pub fn cast<T: NumCast>(&self) -> Option<$Self_<T>> {
+                $(
+                    let $field = match NumCast::from(self.$field) {
+                        Some(x) => x,
+                        None => return None,
+                    };
+                )+
+                Some($Self_ { $($field: $field),+ })
             }
         }
 
@@ -247,9 +347,15 @@ macro_rules! vec(
             }
 
             #[inline]
-            fn from_fixed(_v: [$S; $n]) -> $Self_<$S> {
-                // match v { [$($field),+] => $Self { $($field: $field),+ } }
-                panic!("Unimplemented, pending a fix for rust-lang/rust#16418");
+            fn from_fixed(v: [$S; $n]) -> $Self_<$S> {
+                // Moving out of an array pattern is not yet supported (see
+                // rust-lang/rust#16418), so read the value through a reference
+                // and forget the original array.
+                unsafe {
+                    let result: $Self_<$S> = ptr::read(FixedArray::from_fixed_ref(&v));
+                    mem::forget(v);
+                    result
+                }
             }
 
             #[inline]
@@ -263,6 +369,19 @@ macro_rules! vec(
             }
         }
 
+        /// Collect the components of the vector from an iterator. Any
+        /// items left over are not consumed.
+        ///
+        /// # Panics
+        ///
+        /// If the iterator yields too few items.
+        impl<$S> FromIterator<$S> for $Self_<$S> {
+            fn from_iter<I: IntoIterator<Item=$S>>(iter: I) -> $Self_<$S> {
+                let mut iter = iter.into_iter();
+                $Self_ { $($field: iter.next().expect(concat!("Too few items to collect into a ", stringify!($Self_)))),+ }
+            }
+        }
+
         impl<$S: Copy> Index<usize> for $Self_<$S> {
             type Output = S;
 
@@ -318,6 +437,13 @@ macro_rules! vec(
             #[inline] fn comp_mul(&self) -> S { fold!(mul, { $(self.$field),+ }) }
             #[inline] fn comp_min(&self) -> S { fold!(partial_min, { $(self.$field),+ }) }
             #[inline] fn comp_max(&self) -> S { fold!(partial_max, { $(self.$field),+ }) }
+
+            #[inline] fn l1_norm(&self) -> S { fold!(add, { $(abs(self.$field)),+ }) }
+            #[inline] fn linf_norm(&self) -> S { fold!(partial_max, { $(abs(self.$field)),+ }) }
+
+            #[inline] fn min(&self, v: &$Self_<S>) -> $Self_<S> { $Self_::new($(self.$field.partial_min(v.$field)),+) }
+            #[inline] fn max(&self, v: &$Self_<S>) -> $Self_<S> { $Self_::new($(self.$field.partial_max(v.$field)),+) }
+            #[inline] fn clamp(&self, lo: &$Self_<S>, hi: &$Self_<S>) -> $Self_<S> { self.max(lo).min(hi) }
         }
 
         impl<S: BaseNum> Add for $Self_<S> {
@@ -348,6 +474,13 @@ macro_rules! vec(
             fn mul(self, v: $Self_<S>) -> $Self_<S> { self.mul_v(&v) }
         }
 
+        impl<S: BaseNum> Mul<S> for $Self_<S> {
+            type Output = $Self_<S>;
+
+            #[inline]
+            fn mul(self, s: S) -> $Self_<S> { self.mul_s(s) }
+        }
+
         impl<S: BaseNum> Div for $Self_<S> {
             type Output = $Self_<S>;
 
@@ -355,6 +488,13 @@ macro_rules! vec(
             fn div(self, v: $Self_<S>) -> $Self_<S> { self.div_v(&v) }
         }
 
+        impl<S: BaseNum> Div<S> for $Self_<S> {
+            type Output = $Self_<S>;
+
+            #[inline]
+            fn div(self, s: S) -> $Self_<S> { self.div_s(s) }
+        }
+
         impl<S: BaseNum> Rem for $Self_<S> {
             type Output = $Self_<S>;
 
@@ -367,6 +507,63 @@ macro_rules! vec(
             fn approx_eq_eps(&self, other: &$Self_<S>, epsilon: &S) -> bool {
                 $(self.$field.approx_eq_eps(&other.$field, epsilon))&&+
             }
+
+            #[inline]
+            fn approx_eq_ulps(&self, other: &$Self_<S>, ulps: u32) -> bool {
+                $(self.$field.approx_eq_ulps(&other.$field, ulps))&&+
+            }
+
+            #[inline]
+            fn relative_eq_eps(&self, other: &$Self_<S>, epsilon: &S, max_relative: &S) -> bool {
+                $(self.$field.relative_eq_eps(&other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<S: BaseFloat> $Self_<S> {
+            /// Returns `true` if every component of the vector is finite.
+            #[inline]
+            pub fn is_finite(&self) -> bool { $(self.$field.is_finite())&&+ }
+
+            /// Returns `true` if any component of the vector is NaN.
+            #[inline]
+            pub fn is_nan(&self) -> bool { $(self.$field.is_nan())||+ }
+
+            /// The absolute value of each component.
+            #[inline]
+            pub fn abs(&self) -> $Self_<S> { $Self_ { $($field: self.$field.abs()),+ } }
+
+            /// The largest integer less than or equal to each component.
+            #[inline]
+            pub fn floor(&self) -> $Self_<S> { $Self_ { $($field: self.$field.floor()),+ } }
+
+            /// The smallest integer greater than or equal to each component.
+            #[inline]
+            pub fn ceil(&self) -> $Self_<S> { $Self_ { $($field: self.$field.ceil()),+ } }
+
+            /// The nearest integer to each component, rounding half-way cases
+            /// away from zero.
+            #[inline]
+            pub fn round(&self) -> $Self_<S> { $Self_ { $($field: self.$field.round()),+ } }
+
+            /// The fractional part of each component, `x - x.trunc()`. This
+            /// has the same sign as the component.
+            #[inline]
+            pub fn fract(&self) -> $Self_<S> { $Self_ { $($field: self.$field.fract()),+ } }
+
+            /// The sign of each component. Like `Float::signum`, this is `1`
+            /// for positive components including `+0.0` and infinity, `-1`
+            /// for negative components including `-0.0`, and NaN for NaN.
+            #[inline]
+            pub fn signum(&self) -> $Self_<S> { $Self_ { $($field: self.$field.signum()),+ } }
+
+            /// The magnitude of each component of the vector, with the sign of
+            /// the corresponding component of `sign`. A sign of `-0.0` is
+            /// treated as negative.
+            #[inline]
+            pub fn copysign(&self, sign: &$Self_<S>) -> $Self_<S> {
+                $Self_ { $($field: if sign.$field.is_negative() { -self.$field.abs() }
+                                   else { self.$field.abs() }),+ }
+            }
         }
 
         impl<S: BaseFloat + Rand> Rand for $Self_<S> {
@@ -378,6 +575,12 @@ macro_rules! vec(
     )
 );
 
+/// The absolute value of a number, for both integer and floating point types.
+#[inline]
+fn abs<S: BaseNum>(s: S) -> S {
+    if s < zero() { -s } else { s }
+}
+
 macro_rules! fold {
     (&$method:ident, { $x:expr, $y:expr })                   => { $x.$method(&$y) };
     (&$method:ident, { $x:expr, $y:expr, $z:expr })          => { $x.$method(&$y).$method(&$z) };
@@ -387,6 +590,30 @@ macro_rules! fold {
     ($method:ident, { $x:expr, $y:expr, $z:expr, $w:expr })  => { $x.$method($y).$method($z).$method($w) };
 }
 
+// The structs are declared outside of `vec!`, as `cfg_attr` is not applied to
+// the serialization derives inside of a macro.
+
+/// A 2-dimensional vector.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
+pub struct Vector2<S> { pub x: S, pub y: S }
+
+/// A 3-dimensional vector.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
+pub struct Vector3<S> { pub x: S, pub y: S, pub z: S }
+
+/// A 4-dimensional vector.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde_serialization", derive_serialize)]
+#[cfg_attr(feature = "serde_serialization", derive_deserialize)]
+#[repr(C)]
+pub struct Vector4<S> { pub x: S, pub y: S, pub z: S, pub w: S }
+
 vec!(Vector2<S> { x, y }, 2, vec2);
 vec!(Vector3<S> { x, y, z }, 3, vec3);
 vec!(Vector4<S> { x, y, z, w }, 4, vec4);
@@ -398,7 +625,11 @@ impl<S: BaseNum> Vector2<S> {
     /// A unit vector in the `y` direction.
     #[inline] pub fn unit_y() -> Vector2<S> { Vector2::new(zero(), one()) }
 
-    /// The perpendicular dot product of the vector and `other`.
+    /// The perpendicular dot product of the vector and `other`, also known as
+    /// the 2D cross or wedge product. This is the signed area of the
+    /// parallelogram spanned by the two vectors. It is positive if `other` is
+    /// counter-clockwise from `self`, negative if it is clockwise, and zero if
+    /// they are parallel.
     #[inline]
     pub fn perp_dot(&self, other: &Vector2<S>) -> S {
         (self.x * other.y) - (self.y * other.x)
@@ -410,6 +641,9 @@ impl<S: BaseNum> Vector2<S> {
     pub fn extend(&self, z: S)-> Vector3<S> {
         Vector3::new(self.x, self.y, z)
     }
+
+    /// The `y` and `x` components of the vector, swapped.
+    #[inline] pub fn yx(&self) -> Vector2<S> { Vector2::new(self.y, self.x) }
 }
 
 /// Operations specific to numeric three-dimensional vectors.
@@ -449,6 +683,43 @@ impl<S: BaseNum> Vector3<S> {
     pub fn truncate(&self)-> Vector2<S> {
         Vector2::new(self.x, self.y)
     }
+
+    /// Create a `Vector2`, dropping the nth element
+    #[inline]
+    pub fn truncate_n(&self, n: isize)-> Vector2<S> {
+        match n {
+            0 => Vector2::new(self.y, self.z),
+            1 => Vector2::new(self.x, self.z),
+            2 => Vector2::new(self.x, self.y),
+            _ => panic!("{:?} is out of range", n)
+        }
+    }
+
+    /// The `x` and `y` components of the vector.
+    #[inline] pub fn xy(&self) -> Vector2<S> { Vector2::new(self.x, self.y) }
+    /// The `x` and `z` components of the vector.
+    #[inline] pub fn xz(&self) -> Vector2<S> { Vector2::new(self.x, self.z) }
+    /// The `y` and `z` components of the vector.
+    #[inline] pub fn yz(&self) -> Vector2<S> { Vector2::new(self.y, self.z) }
+}
+
+/// Operations specific to floating point three-dimensional vectors.
+impl<S: BaseFloat> Vector3<S> {
+    /// Find two vectors which, together with the normalized form of this
+    /// vector, make up a right-handed orthonormal basis. The vector must not
+    /// be zero.
+    ///
+    /// This uses the branchless construction from Duff et al., "Building an
+    /// Orthonormal Basis, Revisited", which remains stable for vectors close
+    /// to any axis.
+    pub fn orthonormal_basis(&self) -> (Vector3<S>, Vector3<S>) {
+        let n = self.normalize();
+        let sign = n.z.signum();
+        let a = -one::<S>() / (sign + n.z);
+        let b = n.x * n.y * a;
+        (Vector3::new(one::<S>() + sign * n.x * n.x * a, sign * b, -sign * n.x),
+         Vector3::new(b, sign + n.y * n.y * a, -n.y))
+    }
 }
 
 /// Operations specific to numeric four-dimensional vectors.
@@ -479,6 +750,27 @@ impl<S: BaseNum> Vector4<S> {
             _ => panic!("{:?} is out of range", n)
         }
     }
+
+    /// Perform the perspective divide, returning `(x/w, y/w, z/w)`. This
+    /// takes a clip space position to normalized device coordinates.
+    ///
+    /// Returns `None` if `w` is zero, as is the case for points at infinity
+    /// and for direction vectors.
+    #[inline]
+    pub fn homogenize(&self) -> Option<Vector3<S>> {
+        if self.w == zero() {
+            None
+        } else {
+            Some(Vector3::new(self.x / self.w, self.y / self.w, self.z / self.w))
+        }
+    }
+
+    /// The `x` and `y` components of the vector.
+    #[inline] pub fn xy(&self) -> Vector2<S> { Vector2::new(self.x, self.y) }
+    /// The `x`, `y` and `z` components of the vector.
+    #[inline] pub fn xyz(&self) -> Vector3<S> { Vector3::new(self.x, self.y, self.z) }
+    /// The `x`, `y` and `w` components of the vector.
+    #[inline] pub fn xyw(&self) -> Vector3<S> { Vector3::new(self.x, self.y, self.w) }
 }
 
 /// Specifies geometric operations for vectors. This is only implemented for
@@ -506,48 +798,116 @@ pub trait EuclideanVector<S: BaseFloat>: Vector<S>
         self.dot(self).sqrt()
     }
 
-    /// The angle between the vector and `other`, in radians.
+    /// The angle between the vector and `other`, in radians. This avoids
+    /// taking the `acos` of a normalized dot product, so it stays accurate
+    /// for nearly parallel and antiparallel vectors.
+    ///
+    /// For `Vector2` the angle is signed, and is positive when `other` is
+    /// counter-clockwise from `self`. For the other vector types it lies in
+    /// `[0, pi]`. If either vector has zero length, the angle is zero.
     fn angle(&self, other: &Self) -> Rad<S>;
 
     /// Returns a vector with the same direction, but with a `length` (or
-    /// `norm`) of `1`.
+    /// `norm`) of `1`. The zero vector is returned unchanged.
     #[inline]
     #[must_use]
     fn normalize(&self) -> Self {
         self.normalize_to(one::<S>())
     }
 
-    /// Returns a vector with the same direction and a given `length`.
+    /// Returns a vector with the same direction and a given `length`. The
+    /// zero vector has no direction, so it is returned unchanged.
     #[inline]
     #[must_use]
     fn normalize_to(&self, length: S) -> Self {
+        if self.is_zero() { return Zero::zero(); }
         self.mul_s(length / self.length())
     }
 
-    /// Returns the result of linarly interpolating the length of the vector
-    /// towards the length of `other` by the specified amount.
+    /// Returns a vector with the same direction whose length is clamped to at
+    /// most `max`. Vectors that are already no longer than `max` are returned
+    /// unchanged.
+    #[inline]
+    #[must_use]
+    fn limit_length(&self, max: S) -> Self {
+        let length2 = self.length2();
+        let ratio = if length2 > max * max { max / length2.sqrt() } else { one() };
+        self.mul_s(ratio)
+    }
+
+    /// Returns the result of linearly interpolating the vector towards
+    /// `other` by the specified amount, that is `self + (other - self) *
+    /// amount`. The amount is not clamped, so values outside of `[0, 1]`
+    /// extrapolate beyond the two vectors.
     #[inline]
     #[must_use]
     fn lerp(&self, other: &Self, amount: S) -> Self {
         self.add_v(&other.sub_v(self).mul_s(amount))
     }
 
-    /// Normalises the vector to a length of `1`.
+    /// Returns the vector projection of this vector onto `other`, that is,
+    /// the component of this vector that is parallel to `other`. If `other` is
+    /// the zero vector, the zero vector is returned.
+    #[inline]
+    #[must_use]
+    fn project_on(&self, other: &Self) -> Self {
+        if other.is_zero() { return Zero::zero(); }
+        other.mul_s(self.dot(other) / other.length2())
+    }
+
+    /// Returns the component of this vector that is perpendicular to `other`.
+    /// This is what remains after subtracting the projection onto `other`, so
+    /// if `other` is the zero vector, this vector is returned unchanged.
+    #[inline]
+    #[must_use]
+    fn reject_from(&self, other: &Self) -> Self {
+        self.sub_v(&self.project_on(other))
+    }
+
+    /// Reflect this vector off a surface with the given `normal`, which must
+    /// be of unit length.
+    #[inline]
+    #[must_use]
+    fn reflect(&self, normal: &Self) -> Self {
+        let two = one::<S>() + one::<S>();
+        self.sub_v(&normal.mul_s(two * self.dot(normal)))
+    }
+
+    /// Refract this vector through a surface with the given `normal`,
+    /// following Snell's law. `eta` is the ratio of the refractive index on
+    /// the incident side to that on the far side. Both this vector and the
+    /// normal must be of unit length, with the normal facing against the
+    /// incident vector.
+    ///
+    /// Returns `None` in the case of total internal reflection. At the
+    /// critical angle, the refracted vector runs along the surface.
+    #[must_use]
+    fn refract(&self, normal: &Self, eta: S) -> Option<Self> {
+        let cos_i = self.dot(normal);
+        let k = one::<S>() - eta * eta * (one::<S>() - cos_i * cos_i);
+        if k < zero() { return None; }
+        Some(self.mul_s(eta).sub_v(&normal.mul_s(eta * cos_i + k.sqrt())))
+    }
+
+    /// Normalises the vector to a length of `1`, leaving the zero vector
+    /// unchanged.
     #[inline]
     fn normalize_self(&mut self) {
+        if self.is_zero() { return; }
         let rlen = self.length().recip();
         self.mul_self_s(rlen);
     }
 
-    /// Normalizes the vector to `length`.
+    /// Normalizes the vector to `length`, leaving the zero vector unchanged.
     #[inline]
     fn normalize_self_to(&mut self, length: S) {
+        if self.is_zero() { return; }
         let n = length / self.length();
         self.mul_self_s(n);
     }
 
-    /// Linearly interpolates the length of the vector towards the length of
-    /// `other` by the specified amount.
+    /// Linearly interpolates the vector towards `other` by the specified
+    /// amount, in-place. See `lerp` for details.
     fn lerp_self(&mut self, other: &Self, amount: S) {
         let v = other.sub_v(self).mul_s(amount);
         self.add_self_v(&v);
@@ -571,7 +931,12 @@ impl<S: BaseFloat> EuclideanVector<S> for Vector3<S> {
 impl<S: BaseFloat> EuclideanVector<S> for Vector4<S> {
     #[inline]
     fn angle(&self, other: &Vector4<S>) -> Rad<S> {
-        acos(self.dot(other) / (self.length() * other.length()))
+        // There is no cross product in four dimensions, so use Kahan's
+        // formula, which is well conditioned over the whole range.
+        let a = self.mul_s(other.length());
+        let b = other.mul_s(self.length());
+        let two: S = cast(2i8).unwrap();
+        atan2(a.sub_v(&b).length(), a.add_v(&b).length()).mul_s(two)
     }
 }
 
@@ -103,3 +103,66 @@ fn test_bound() {
     assert_eq!(aabb.relate_plane(&plane2), Relation::In);
     assert_eq!(aabb.relate_plane(&plane3), Relation::Out);
 }
+
+#[test]
+fn test_boundary() {
+    let aabb = Aabb2::new(Point2::new(0isize, 0isize), Point2::new(10isize, 10isize));
+    assert!(aabb.contains(&Point2::new(0isize, 0isize)));
+    assert!(aabb.contains(&Point2::new(0isize, 9isize)));
+    assert!(!aabb.contains(&Point2::new(10isize, 5isize)));
+    assert!(!aabb.contains(&Point2::new(5isize, 10isize)));
+    assert!(!aabb.contains(&Point2::new(10isize, 10isize)));
+
+    let aabb = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    assert!(aabb.contains(&Point3::new(0.0f64, 0.5, 0.5)));
+    assert!(!aabb.contains(&Point3::new(1.0f64, 0.5, 0.5)));
+    assert!(!aabb.contains(&Point3::new(0.5f64, 0.5, -0.0001)));
+}
+
+#[test]
+fn test_degenerate() {
+    let p = Point3::new(1isize, 2isize, 3isize);
+    let aabb = Aabb3::new(p, p);
+    assert_eq!(aabb.dim(), Vector3::new(0isize, 0isize, 0isize));
+    assert_eq!(aabb.volume(), 0isize);
+    assert_eq!(aabb.center(), p);
+    assert!(!aabb.contains(&p));
+
+    // a flat box still has a well-defined extent along the other axes
+    let aabb = Aabb2::new(Point2::new(0.0f32, 5.0), Point2::new(4.0, 5.0));
+    assert_eq!(aabb.dim(), Vector2::new(4.0f32, 0.0));
+    assert_eq!(aabb.volume(), 0.0f32);
+    assert!(!aabb.contains(&Point2::new(2.0f32, 5.0)));
+
+    // growing a degenerate box gives it a volume
+    let aabb = Aabb3::new(p, p).grow(&Point3::new(2isize, 4isize, 6isize));
+    assert_eq!(aabb, Aabb3::new(p, Point3::new(2isize, 4isize, 6isize)));
+    assert_eq!(aabb.volume(), 6isize);
+}
+
+#[test]
+fn test_ray3_intersect() {
+    let aabb = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+
+    // down each axis
+    let rx = Ray::new(Point3::new(-2.0f64, 0.5, 0.5), Vector3::new(1.0, 0.0, 0.0));
+    let ry = Ray::new(Point3::new(0.5f64, 3.0, 0.5), Vector3::new(0.0, -1.0, 0.0));
+    let rz = Ray::new(Point3::new(0.5f64, 0.5, -4.0), Vector3::new(0.0, 0.0, 2.0));
+    assert_eq!(aabb.intersects_ray(&rx), Some(2.0));
+    assert_eq!(aabb.intersects_ray(&ry), Some(2.0));
+    assert_eq!(aabb.intersects_ray(&rz), Some(2.0));
+    assert_eq!((rx, aabb).intersection(), Some(Point3::new(0.0f64, 0.5, 0.5)));
+
+    // starting inside
+    let inside = Ray::new(Point3::new(0.5f64, 0.5, 0.5), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(aabb.intersects_ray(&inside), Some(0.0));
+
+    // misses: parallel outside the slab, pointing away, and passing to the side
+    let parallel = Ray::new(Point3::new(-2.0f64, 2.0, 0.5), Vector3::new(1.0, 0.0, 0.0));
+    let away = Ray::new(Point3::new(-2.0f64, 0.5, 0.5), Vector3::new(-1.0, 0.0, 0.0));
+    let side = Ray::new(Point3::new(-2.0f64, 0.0, 0.0), Vector3::new(1.0, 2.0, 0.0));
+    assert_eq!(aabb.intersects_ray(&parallel), None);
+    assert_eq!(aabb.intersects_ray(&away), None);
+    assert_eq!(aabb.intersects_ray(&side), None);
+    assert_eq!((parallel, aabb).intersection(), None);
+}
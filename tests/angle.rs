@@ -16,7 +16,7 @@
 extern crate cgmath;
 
 use cgmath::{Angle, Rad, Deg, rad, deg};
-use cgmath::{ToRad, ToDeg};
+use cgmath::{ToRad, ToDeg, sin, cos};
 use cgmath::ApproxEq;
 
 #[test]
@@ -38,3 +38,69 @@ fn equiv() {
     assert!(Rad::<f32>::turn_div_2().equiv(&-Rad::<f32>::turn_div_2()));
     assert!(Rad::<f32>::turn_div_3().sub_a(Rad::<f32>::full_turn()).equiv(&Rad::<f32>::turn_div_3()));
 }
+
+#[test]
+fn normalize() {
+    use std::f64::consts::PI;
+
+    assert!(rad(3.0 * PI).normalize().approx_eq(&rad(PI)));
+    assert!(rad(-PI / 2.0).normalize().approx_eq(&rad(1.5 * PI)));
+    assert_eq!(rad(0.0f64).normalize(), rad(0.0f64));
+    assert_eq!(rad(-1e-20f64).normalize(), rad(0.0f64));
+    assert_eq!(deg(5.0 * 360.0f64).normalize(), deg(0.0f64));
+    assert_eq!(deg(-30.0f64).normalize(), deg(330.0f64));
+    assert_eq!(deg(-720.0f64).normalize(), deg(0.0f64));
+}
+
+#[test]
+fn normalize_signed() {
+    use std::f64::consts::PI;
+
+    assert!(rad(3.0 * PI).normalize_signed().approx_eq(&rad(-PI)));
+    assert!(rad(-PI / 2.0).normalize_signed().approx_eq(&rad(-PI / 2.0)));
+    assert!(rad(1.5 * PI).normalize_signed().approx_eq(&rad(-PI / 2.0)));
+    assert_eq!(deg(5.0 * 360.0f64).normalize_signed(), deg(0.0f64));
+    assert_eq!(deg(180.0f64).normalize_signed(), deg(-180.0f64));
+    assert_eq!(deg(-180.0f64).normalize_signed(), deg(-180.0f64));
+    assert_eq!(deg(190.0f64).normalize_signed(), deg(-170.0f64));
+    assert_eq!(deg(-190.0f64).normalize_signed(), deg(170.0f64));
+}
+
+#[test]
+fn sub_wrapped() {
+    use std::f64::consts::PI;
+
+    assert!(deg(10.0f64).sub_wrapped(deg(350.0f64)).approx_eq(&deg(20.0f64)));
+    assert!(deg(350.0f64).sub_wrapped(deg(10.0f64)).approx_eq(&deg(-20.0f64)));
+    assert!(deg(90.0f64).sub_wrapped(deg(45.0f64)).approx_eq(&deg(45.0f64)));
+    assert!(deg(-170.0f64).sub_wrapped(deg(170.0f64)).approx_eq(&deg(20.0f64)));
+    assert!(deg(720.0f64).sub_wrapped(deg(0.0f64)).approx_eq(&deg(0.0f64)));
+
+    assert!(rad(0.1f64).sub_wrapped(rad(2.0 * PI - 0.1)).approx_eq(&rad(0.2f64)));
+    assert!(rad(2.0 * PI - 0.1).sub_wrapped(rad(0.1f64)).approx_eq(&rad(-0.2f64)));
+}
+
+#[test]
+fn test_sin_cos() {
+    for &x in [0.0f64, 0.3, 1.0, -2.5, 4.0].iter() {
+        let (s, c) = rad(x).sin_cos();
+        assert_eq!(s, sin(rad(x)));
+        assert_eq!(c, cos(rad(x)));
+    }
+
+    let (s, c) = deg(30.0f64).sin_cos();
+    assert!(s.approx_eq(&0.5));
+    assert!(c.approx_eq(&0.8660254037844386));
+}
+
+#[test]
+fn test_turns() {
+    use std::f64::consts::PI;
+
+    assert!(Rad::<f64>::full_turn().approx_eq(&rad(2.0 * PI)));
+    assert!(Deg::<f64>::full_turn().to_rad().approx_eq(&Rad::full_turn()));
+    assert!(Rad::<f64>::half_turn().approx_eq(&rad(PI)));
+    assert_eq!(Deg::<f64>::half_turn(), deg(180.0));
+    assert_eq!(Deg::<f64>::turn_div_4(), deg(90.0));
+    assert!(Rad::<f64>::turn_div_6().approx_eq(&rad(PI / 3.0)));
+}
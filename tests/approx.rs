@@ -17,6 +17,8 @@
 extern crate cgmath;
 
 use cgmath::*;
+use std::f64;
+use std::{i32, u32};
 
 #[test]
 fn macro_assert_approx_eq_eps() {
@@ -39,3 +41,94 @@ fn macro_assert_approx_eq() {
 fn macro_assert_approx_eq_fail() {
     assert_approx_eq!(1.0f64 / 3.0, 0.333);
 }
+
+#[test]
+fn approx_eq_eps() {
+    // accumulated error that is too large for the default epsilon
+    let err = 1.0e-4f64;
+
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+    let w = Vector3::new(1.0f64 + err, 2.0, 3.0 - err);
+    assert!(!v.approx_eq(&w));
+    assert!(v.approx_eq_eps(&w, &1.0e-3));
+
+    let m = Matrix3::<f64>::identity();
+    let n = Matrix3::new(1.0f64, err, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0 + err);
+    assert!(!m.approx_eq(&n));
+    assert!(m.approx_eq_eps(&n, &1.0e-3));
+
+    let q = Quaternion::new(1.0f64, 0.0, 0.0, 0.0);
+    let r = Quaternion::new(1.0f64 - err, err, 0.0, 0.0);
+    assert!(!q.approx_eq(&r));
+    assert!(q.approx_eq_eps(&r, &1.0e-3));
+
+    assert!(!rad(1.0f64).approx_eq(&rad(1.0 + err)));
+    assert!(rad(1.0f64).approx_eq_eps(&rad(1.0 + err), &1.0e-3));
+    assert!(!deg(90.0f64).approx_eq(&deg(90.0 + err)));
+    assert!(deg(90.0f64).approx_eq_eps(&deg(90.0 + err), &1.0e-3));
+}
+
+#[test]
+fn approx_eq_ulps() {
+    use std::{f32, f64};
+
+    // just above 1, one ulp is the machine epsilon
+    let a = 1.0f32;
+    let b = 1.0f32 + 3.0 * f32::EPSILON;
+    assert!(a.approx_eq_ulps(&b, 3));
+    assert!(b.approx_eq_ulps(&a, 3));
+    assert!(!a.approx_eq_ulps(&b, 2));
+    assert!(a.approx_eq_ulps(&a, 0));
+    assert!(!a.approx_eq_ulps(&(1.0f32 + f32::EPSILON), 0));
+
+    // the tolerance scales with the magnitude
+    let big = 1048576.0f64;
+    assert!(big.approx_eq_ulps(&(big + big * f64::EPSILON), 1));
+    assert!(!big.approx_eq_ulps(&(big + 1.0e-4), 1));
+    assert!(!1.0e-10f64.approx_eq_ulps(&2.0e-10f64, 1000));
+
+    // signs and special values
+    assert!(0.0f64.approx_eq_ulps(&-0.0f64, 0));
+    assert!(!1.0e-45f32.approx_eq_ulps(&-1.0e-45f32, 10));
+    assert!(!f64::NAN.approx_eq_ulps(&f64::NAN, 10));
+    assert!(f64::INFINITY.approx_eq_ulps(&f64::INFINITY, 0));
+
+    // tolerances too large for the signed bit patterns still accept, rather
+    // than wrapping around and rejecting
+    assert!(1.0f32.approx_eq_ulps(&1.0e30f32, u32::MAX));
+    assert!(1.0f32.approx_eq_ulps(&1.0e30f32, i32::MAX as u32 + 1));
+    let c = 1.0f64 + 2147483648.0 * f64::EPSILON; // 2^31 ULP above 1
+    assert!(1.0f64.approx_eq_ulps(&c, i32::MAX as u32 + 1));
+    assert!(!1.0f64.approx_eq_ulps(&c, i32::MAX as u32));
+
+    let v = Vector3::new(1.0f32, 2.0, 4.0);
+    let w = Vector3::new(1.0f32 + f32::EPSILON, 2.0, 4.0 + 8.0 * f32::EPSILON);
+    assert!(v.approx_eq_ulps(&w, 2));
+    assert!(!v.approx_eq_ulps(&w, 1));
+
+    let m = Matrix2::new(1.0f32, 2.0, 4.0, 1.0);
+    let n = Matrix2::new(1.0f32, 2.0, 4.0 + 8.0 * f32::EPSILON, 1.0 + 2.0 * f32::EPSILON);
+    assert!(m.approx_eq_ulps(&n, 2));
+    assert!(!m.approx_eq_ulps(&n, 1));
+}
+
+#[test]
+fn relative_eq() {
+    // far beyond the default absolute epsilon, but relatively close
+    assert!(!1.0e9f64.approx_eq(&(1.0e9 + 1.0)));
+    assert!(1.0e9f64.relative_eq(&(1.0e9 + 1.0), &1.0e-8));
+    assert!(!1.0e9f64.relative_eq(&(1.0e9 + 100.0), &1.0e-8));
+
+    // the absolute epsilon handles values on either side of zero
+    assert!(1.0e-7f64.relative_eq(&-1.0e-7, &1.0e-8));
+    assert!(!1.0e-7f64.relative_eq_eps(&-1.0e-7, &0.0, &1.0e-8));
+
+    assert!(f64::INFINITY.relative_eq(&f64::INFINITY, &1.0e-8));
+    assert!(!f64::INFINITY.relative_eq(&f64::NEG_INFINITY, &1.0e-8));
+    assert!(!f64::NAN.relative_eq(&f64::NAN, &1.0e-8));
+
+    let v = Vector3::new(1.0e9f32, -2.0e-9, 3.0e3);
+    let w = Vector3::new(1.0e9f32 + 64.0, 0.0, 3.0e3 + 1.0e-4);
+    assert!(!v.approx_eq(&w));
+    assert!(v.relative_eq(&w, &1.0e-6));
+}
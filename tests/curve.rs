@@ -0,0 +1,112 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Point, Point2, Point3, Vector, Vector2};
+use cgmath::{catmull_rom, bezier2, bezier3, bezier3_tangent};
+use cgmath::ApproxEq;
+
+#[test]
+fn test_catmull_rom_endpoints() {
+    let p0 = Point3::new(0.0f64, 0.0, 0.0);
+    let p1 = Point3::new(1.0f64, 2.0, 0.0);
+    let p2 = Point3::new(3.0f64, 3.0, 1.0);
+    let p3 = Point3::new(4.0f64, 1.0, 2.0);
+
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 0.0).approx_eq(&p1));
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 1.0).approx_eq(&p2));
+}
+
+#[test]
+fn test_catmull_rom_collinear() {
+    // Evenly spaced collinear points are traversed at constant speed.
+    let p0 = Point3::new(0.0f64, 0.0, 0.0);
+    let p1 = Point3::new(1.0f64, 0.0, 0.0);
+    let p2 = Point3::new(2.0f64, 0.0, 0.0);
+    let p3 = Point3::new(3.0f64, 0.0, 0.0);
+
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 0.25).approx_eq(&Point3::new(1.25, 0.0, 0.0)));
+    assert!(catmull_rom(&p0, &p1, &p2, &p3, 0.5).approx_eq(&Point3::new(1.5, 0.0, 0.0)));
+}
+
+#[test]
+fn test_catmull_rom_continuity() {
+    let p0 = Point3::new(0.0f64, 0.0, 0.0);
+    let p1 = Point3::new(1.0f64, 2.0, 0.0);
+    let p2 = Point3::new(3.0f64, 3.0, 1.0);
+    let p3 = Point3::new(4.0f64, 1.0, 2.0);
+    let p4 = Point3::new(6.0f64, 0.0, 2.0);
+
+    // Compare one-sided derivatives at the join between the two segments.
+    let h = 1e-6;
+    let end = catmull_rom(&p0, &p1, &p2, &p3, 1.0);
+    let before = catmull_rom(&p0, &p1, &p2, &p3, 1.0 - h);
+    let start = catmull_rom(&p1, &p2, &p3, &p4, 0.0);
+    let after = catmull_rom(&p1, &p2, &p3, &p4, h);
+
+    assert!(end.approx_eq(&start));
+
+    let incoming = end.sub_p(&before).div_s(h);
+    let outgoing = after.sub_p(&start).div_s(h);
+    assert!(incoming.approx_eq_eps(&outgoing, &1e-4));
+
+    // The tangent at the join is half the chord between its neighbours.
+    assert!(incoming.approx_eq_eps(&p3.sub_p(&p1).mul_s(0.5), &1e-4));
+}
+
+#[test]
+fn test_bezier2() {
+    let p0 = Point2::new(0.0f64, 0.0);
+    let p1 = Point2::new(1.0f64, 2.0);
+    let p2 = Point2::new(2.0f64, 0.0);
+
+    assert_eq!(bezier2(&p0, &p1, &p2, 0.0), p0);
+    assert_eq!(bezier2(&p0, &p1, &p2, 1.0), p2);
+    assert!(bezier2(&p0, &p1, &p2, 0.5).approx_eq(&Point2::new(1.0, 1.0)));
+}
+
+#[test]
+fn test_bezier3() {
+    let p0 = Point3::new(0.1f64, 0.0, 0.3);
+    let p1 = Point3::new(1.0f64, 3.0, 0.0);
+    let p2 = Point3::new(3.0f64, 3.0, 0.0);
+    let p3 = Point3::new(4.7f64, 0.0, 0.9);
+
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 0.0), p0);
+    assert_eq!(bezier3(&p0, &p1, &p2, &p3, 1.0), p3);
+
+    // (p0 + 3 p1 + 3 p2 + p3) / 8
+    assert!(bezier3(&p0, &p1, &p2, &p3, 0.5).approx_eq(&Point3::new(2.1, 2.25, 0.15)));
+}
+
+#[test]
+fn test_bezier3_tangent() {
+    let p0 = Point2::new(0.0f64, 0.0);
+    let p1 = Point2::new(1.0f64, 3.0);
+    let p2 = Point2::new(3.0f64, 3.0);
+    let p3 = Point2::new(4.0f64, 0.0);
+
+    // The end tangents point along the control polygon.
+    assert!(bezier3_tangent(&p0, &p1, &p2, &p3, 0.0).approx_eq(&Vector2::new(3.0, 9.0)));
+    assert!(bezier3_tangent(&p0, &p1, &p2, &p3, 1.0).approx_eq(&Vector2::new(3.0, -9.0)));
+    // 3/4 (p2 + p3 - p0 - p1)
+    assert!(bezier3_tangent(&p0, &p1, &p2, &p3, 0.5).approx_eq(&Vector2::new(4.5, 0.0)));
+
+    let h = 1e-6;
+    let t = 0.3;
+    let numeric = bezier3(&p0, &p1, &p2, &p3, t + h).sub_p(&bezier3(&p0, &p1, &p2, &p3, t - h)).div_s(2.0 * h);
+    assert!(bezier3_tangent(&p0, &p1, &p2, &p3, t).approx_eq_eps(&numeric, &1e-6));
+}
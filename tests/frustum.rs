@@ -16,6 +16,7 @@
 extern crate cgmath;
 
 use cgmath::{PerspectiveFov, Point3, Projection, Relation, Sphere, rad};
+use cgmath::{Frustum, ToMatrix4};
 
 #[test]
 fn test_contains() {
@@ -38,3 +39,38 @@ fn test_contains() {
             radius: 1f32,
         }), Relation::Out);
 }
+
+#[test]
+fn test_contains_point() {
+    let proj = PerspectiveFov {
+        fovy: rad(1f32),
+        aspect: 1f32,
+        near: 1f32,
+        far: 10f32,
+    };
+    let frustum = Frustum::from_matrix4(proj.to_matrix4()).unwrap();
+
+    assert!(frustum.contains_point(&Point3::new(0f32, 0f32, -5f32)));
+    assert!(frustum.contains_point(&Point3::new(0f32, 0f32, -1f32)));
+    assert!(frustum.contains_point(&Point3::new(0f32, 0f32, -10f32)));
+    assert!(!frustum.contains_point(&Point3::new(0f32, 0f32, -0.9f32)));
+    assert!(!frustum.contains_point(&Point3::new(0f32, 0f32, -10.1f32)));
+    assert!(!frustum.contains_point(&Point3::new(0f32, 5f32, -5f32)));
+    assert!(!frustum.contains_point(&Point3::new(-5f32, 0f32, -5f32)));
+}
+
+#[test]
+fn test_contains_sphere() {
+    let frustum = PerspectiveFov {
+        fovy: rad(1f32),
+        aspect: 1f32,
+        near: 1f32,
+        far: 10f32,
+    }.to_frustum();
+    let sphere = |z: f32| Sphere::new(Point3::new(0f32, 0f32, z), 0.5f32);
+    assert_eq!(frustum.contains_sphere(&sphere(-5f32)), Relation::In);
+    assert_eq!(frustum.contains_sphere(&sphere(-1f32)), Relation::Cross);
+    assert_eq!(frustum.contains_sphere(&sphere(-10f32)), Relation::Cross);
+    assert_eq!(frustum.contains_sphere(&sphere(0f32)), Relation::Out);
+    assert_eq!(frustum.contains_sphere(&sphere(-11f32)), Relation::Out);
+}
@@ -0,0 +1,82 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The math types are `#[repr(C)]` so that they can be passed directly to
+//! graphics APIs. These tests check that their layout matches the
+//! equivalent C structs.
+
+extern crate cgmath;
+
+use std::mem;
+
+use cgmath::*;
+
+fn offset<T, F>(base: &T, field: &F) -> usize {
+    field as *const F as usize - base as *const T as usize
+}
+
+#[test]
+fn test_vector_layout() {
+    assert_eq!(mem::size_of::<Vector2<f32>>(), 8);
+    assert_eq!(mem::size_of::<Vector3<f32>>(), 12);
+    assert_eq!(mem::size_of::<Vector4<f32>>(), 16);
+    assert_eq!(mem::min_align_of::<Vector4<f32>>(), mem::min_align_of::<f32>());
+    assert_eq!(mem::size_of::<Vector3<f64>>(), 24);
+
+    let v = Vector4::new(1.0f32, 2.0, 3.0, 4.0);
+    assert_eq!(offset(&v, &v.x), 0);
+    assert_eq!(offset(&v, &v.y), 4);
+    assert_eq!(offset(&v, &v.z), 8);
+    assert_eq!(offset(&v, &v.w), 12);
+}
+
+#[test]
+fn test_point_layout() {
+    assert_eq!(mem::size_of::<Point2<f32>>(), 8);
+    assert_eq!(mem::size_of::<Point3<f32>>(), 12);
+    assert_eq!(mem::min_align_of::<Point3<f32>>(), mem::min_align_of::<f32>());
+
+    let p = Point3::new(1.0f32, 2.0, 3.0);
+    assert_eq!(offset(&p, &p.x), 0);
+    assert_eq!(offset(&p, &p.y), 4);
+    assert_eq!(offset(&p, &p.z), 8);
+}
+
+#[test]
+fn test_matrix_layout() {
+    assert_eq!(mem::size_of::<Matrix2<f32>>(), 16);
+    assert_eq!(mem::size_of::<Matrix3<f32>>(), 36);
+    assert_eq!(mem::size_of::<Matrix4<f32>>(), 64);
+    assert_eq!(mem::min_align_of::<Matrix4<f32>>(), mem::min_align_of::<f32>());
+
+    let m = Matrix4::<f32>::identity();
+    assert_eq!(offset(&m, &m.x), 0);
+    assert_eq!(offset(&m, &m.y), 16);
+    assert_eq!(offset(&m, &m.z), 32);
+    assert_eq!(offset(&m, &m.w), 48);
+    assert_eq!(offset(&m, &m.z.y), 36);
+}
+
+#[test]
+fn test_quaternion_layout() {
+    assert_eq!(mem::size_of::<Quaternion<f32>>(), 16);
+    assert_eq!(mem::min_align_of::<Quaternion<f32>>(), mem::min_align_of::<f32>());
+
+    let q = Quaternion::new(1.0f32, 2.0, 3.0, 4.0);
+    assert_eq!(offset(&q, &q.s), 0);
+    assert_eq!(offset(&q, &q.v.x), 4);
+    assert_eq!(offset(&q, &q.v.y), 8);
+    assert_eq!(offset(&q, &q.v.z), 12);
+}
@@ -65,3 +65,49 @@ fn test_line_intersection() {
     let l9 = Line::new(Point2::new(-1.0f32, 0.0), Point2::new(-1.0, 0.0));
     assert_eq!((r9, l9).intersection(), None);
 }
+
+#[test]
+fn test_closest_point() {
+    let l = Line::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(4.0f64, 0.0, 0.0));
+
+    // Perpendicular to the middle of the segment
+    assert!(l.closest_point(&Point3::new(1.0f64, 2.0, 0.0)).approx_eq(&Point3::new(1.0, 0.0, 0.0)));
+    assert!(l.distance_to_point(&Point3::new(1.0f64, 2.0, 0.0)).approx_eq(&2.0));
+
+    // Beyond either end point, the projection is clamped.
+    assert!(l.closest_point(&Point3::new(-3.0f64, 4.0, 0.0)).approx_eq(&l.origin));
+    assert!(l.distance_to_point(&Point3::new(-3.0f64, 4.0, 0.0)).approx_eq(&5.0));
+    assert!(l.closest_point(&Point3::new(7.0f64, 0.0, 4.0)).approx_eq(&l.dest));
+    assert!(l.distance_to_point(&Point3::new(7.0f64, 0.0, 4.0)).approx_eq(&5.0));
+
+    let l = Line::new(Point2::new(1.0f64, 1.0), Point2::new(1.0f64, 1.0));
+    assert!(l.closest_point(&Point2::new(5.0f64, 1.0)).approx_eq(&Point2::new(1.0, 1.0)));
+}
+
+#[test]
+fn test_closest_points_between() {
+    // Skew segments crossing in projection
+    let a = Line::new(Point3::new(-1.0f64, 0.0, 0.0), Point3::new(1.0f64, 0.0, 0.0));
+    let b = Line::new(Point3::new(0.0f64, -1.0, 2.0), Point3::new(0.0f64, 1.0, 2.0));
+    let (p, q) = a.closest_points_between(&b);
+    assert!(p.approx_eq(&Point3::new(0.0, 0.0, 0.0)));
+    assert!(q.approx_eq(&Point3::new(0.0, 0.0, 2.0)));
+
+    // The closest point on `b` would lie beyond its end, so both are clamped.
+    let b = Line::new(Point3::new(3.0f64, 1.0, 1.0), Point3::new(3.0f64, 5.0, 1.0));
+    let (p, q) = a.closest_points_between(&b);
+    assert!(p.approx_eq(&Point3::new(1.0, 0.0, 0.0)));
+    assert!(q.approx_eq(&Point3::new(3.0, 1.0, 1.0)));
+
+    // Parallel segments
+    let b = Line::new(Point3::new(2.0f64, 1.0, 0.0), Point3::new(5.0f64, 1.0, 0.0));
+    let (p, q) = a.closest_points_between(&b);
+    assert!(p.approx_eq(&Point3::new(1.0, 0.0, 0.0)));
+    assert!(q.approx_eq(&Point3::new(2.0, 1.0, 0.0)));
+
+    // Degenerate segment
+    let b = Line::new(Point3::new(0.5f64, 3.0, 0.0), Point3::new(0.5f64, 3.0, 0.0));
+    let (p, q) = a.closest_points_between(&b);
+    assert!(p.approx_eq(&Point3::new(0.5, 0.0, 0.0)));
+    assert!(q.approx_eq(&b.origin));
+}
@@ -233,6 +233,10 @@ fn test_trace() {
     assert_eq!(matrix2::A.trace(), 5.0f64);
     assert_eq!(matrix3::A.trace(), 15.0f64);
     assert_eq!(matrix4::A.trace(), 34.0f64);
+
+    // the trace of a rotation matrix is `1 + 2cos(θ)`
+    let rot = Matrix3::from_axis_angle(&Vector3::new(0.0f64, 0.6, 0.8), rad(0.5f64));
+    assert!(rot.trace().approx_eq(&(1.0f64 + 2.0 * cos(rad(0.5f64)))));
 }
 
 #[test]
@@ -329,6 +333,66 @@ fn test_invert() {
     assert!(mat_f.invert().unwrap().mul_m(&mat_f).is_identity());
 }
 
+#[test]
+fn test_from_euler() {
+    let angles = [(0.0f64, 0.0f64, 0.0f64), (1.0, 0.0, 0.0), (0.0, -1.0, 0.0), (0.0, 0.0, 2.5),
+                  (0.3, -1.1, 2.0), (-2.0, 1.5, -0.7), (0.1, f64::consts::FRAC_PI_2, 0.4)];
+    for &(x, y, z) in angles.iter() {
+        let m = Matrix3::from_euler(rad(x), rad(y), rad(z));
+        let q: Quaternion<f64> = Rotation3::from_euler(rad(x), rad(y), rad(z));
+        assert!(m.approx_eq(&q.to_matrix3()));
+
+        let composed = Matrix3::from_angle_z(rad(z))
+                         .mul_m(&Matrix3::from_angle_y(rad(y)))
+                         .mul_m(&Matrix3::from_angle_x(rad(x)));
+        assert!(m.approx_eq(&composed));
+    }
+}
+
+#[test]
+fn test_orthonormalize() {
+    let rot = Matrix3::from_euler(rad(0.3f64), rad(-1.1f64), rad(2.0f64));
+    let drifted = rot.add_m(&Matrix3::new(0.01f64, -0.02, 0.005,
+                                          0.003,   0.01, -0.01,
+                                          -0.02,   0.0,   0.015));
+    assert!(!drifted.mul_m(&drifted.transpose()).approx_eq(&Matrix3::identity()));
+
+    let m = drifted.orthonormalize();
+    assert!(m.mul_m(&m.transpose()).approx_eq(&Matrix3::identity()));
+    assert!(m.x.approx_eq(&drifted.x.normalize()));
+    assert!(m.determinant().approx_eq(&1.0f64));
+
+    let mut mut_drifted = drifted;
+    mut_drifted.orthonormalize_self();
+    assert_eq!(mut_drifted, m);
+
+    // parallel columns give a best effort result
+    let degenerate = Matrix3::new(2.0f64, 0.0, 0.0,
+                                  4.0f64, 0.0, 0.0,
+                                  0.0f64, 0.0, 0.0);
+    let m = degenerate.orthonormalize();
+    assert!(m.mul_m(&m.transpose()).approx_eq(&Matrix3::identity()));
+    assert_eq!(m.x, Vector3::unit_x());
+}
+
+#[test]
+fn test_invert_affine() {
+    let rot = Matrix3::from_axis_angle(&Vector3::new(1.0f64, 2.0, -1.0).normalize(), rad(0.8f64));
+    let rigid = rot.to_matrix4().mul_m(&Matrix4::from_translation(&Vector3::new(3.0f64, -4.0, 5.0)));
+
+    assert!(rigid.invert_rigid().approx_eq(&rigid.invert().unwrap()));
+    assert!(rigid.invert_affine().unwrap().approx_eq(&rigid.invert().unwrap()));
+    assert!(rigid.invert_rigid().mul_m(&rigid).approx_eq(&Matrix4::identity()));
+
+    // affine matrices with a scale can only be inverted by `invert_affine`
+    let scaled = rigid.mul_m(&Matrix3::from_diagonal(&Vector3::new(2.0f64, 0.5, 3.0)).to_matrix4());
+    assert!(scaled.invert_affine().unwrap().approx_eq(&scaled.invert().unwrap()));
+    assert!(!scaled.invert_rigid().approx_eq(&scaled.invert().unwrap()));
+
+    let singular = Matrix3::from_diagonal(&Vector3::new(1.0f64, 0.0, 1.0)).to_matrix4();
+    assert_eq!(singular.invert_affine(), None);
+}
+
 #[test]
 fn test_from_translation() {
     let mat = Matrix4::from_translation(&Vector3::new(1.0f64, 2.0f64, 3.0f64));
@@ -411,3 +475,452 @@ fn test_from_angle() {
     let rot3: Matrix2<f64> = Matrix2::from_angle(rad(f64::consts::PI));
     assert!(rot3.mul_v(&Vector2::new(1.0, 1.0)).approx_eq(&Vector2::new(-1.0, -1.0)));
 }
+
+#[test]
+fn test_row() {
+    assert_eq!(matrix2::A.row(0), Vector2::new(1.0f64, 2.0f64));
+    assert_eq!(matrix2::A.row(1), Vector2::new(3.0f64, 4.0f64));
+
+    assert_eq!(matrix4::A.row(0), Vector4::new(1.0f64, 2.0f64, 3.0f64, 4.0f64));
+    assert_eq!(matrix4::A.row(1), Vector4::new(5.0f64, 6.0f64, 7.0f64, 8.0f64));
+    assert_eq!(matrix4::A.row(2), Vector4::new(9.0f64, 10.0f64, 11.0f64, 12.0f64));
+    assert_eq!(matrix4::A.row(3), Vector4::new(13.0f64, 14.0f64, 15.0f64, 16.0f64));
+
+    // Dotting a row with a vector gives the corresponding component of the
+    // product.
+    assert_eq!(matrix4::A.row(2).dot(&matrix4::V), matrix4::A.mul_v(&matrix4::V).z);
+}
+
+#[test]
+#[should_panic]
+fn test_row_out_of_range() {
+    matrix3::A.row(3);
+}
+
+#[test]
+fn test_swap() {
+    let mut m = matrix3::A;
+    m.swap_rows(0, 2);
+    assert_eq!(m.row(0), matrix3::A.row(2));
+    assert_eq!(m.row(1), matrix3::A.row(1));
+    assert_eq!(m.row(2), matrix3::A.row(0));
+
+    let mut m = matrix3::A;
+    m.swap_cols(0, 1);
+    assert_eq!(m[0], matrix3::A[1]);
+    assert_eq!(m[1], matrix3::A[0]);
+    assert_eq!(m[2], matrix3::A[2]);
+}
+
+#[test]
+fn test_from_diagonal() {
+    let v2 = Vector2::new(2.0f64, -3.0f64);
+    assert_eq!(Matrix2::from_diagonal(&v2), Matrix2::new(2.0f64, 0.0f64,
+                                                         0.0f64, -3.0f64));
+    assert_eq!(Matrix2::from_diagonal(&v2).diagonal(), v2);
+
+    let v3 = Vector3::new(1.0f64, 2.0f64, 3.0f64);
+    assert_eq!(Matrix3::from_diagonal(&v3).diagonal(), v3);
+    assert!(Matrix3::from_diagonal(&v3).is_diagonal());
+    assert_eq!(Matrix3::from_diagonal(&Vector3::new(1.0f64, 1.0f64, 1.0f64)), Matrix3::identity());
+
+    let v4 = Vector4::new(4.0f64, 0.5f64, -1.0f64, 1.0f64);
+    assert_eq!(Matrix4::from_diagonal(&v4).diagonal(), v4);
+    assert!(Matrix4::from_diagonal(&v4).is_diagonal());
+    assert_eq!(Matrix4::from_diagonal(&v4).mul_v(&matrix4::V), v4.mul_v(&matrix4::V));
+}
+
+#[test]
+fn test_singular_and_symmetric() {
+    // Two equal columns make the matrix singular.
+    let c = Vector3::new(1.0f64, 2.0f64, 3.0f64);
+    let singular = Matrix3::from_cols(c, Vector3::new(0.0f64, 1.0f64, 0.0f64), c);
+    assert!(!singular.is_invertible());
+    assert!(singular.invert().is_none());
+
+    let singular = Matrix4::from_cols(matrix4::V, matrix4::V, matrix4::D.z, matrix4::D.w);
+    assert!(!singular.is_invertible());
+    assert!(singular.invert().is_none());
+
+    assert!(matrix4::D.is_symmetric());
+    assert!(matrix4::D.is_invertible());
+    assert!(!matrix4::C.is_symmetric());
+    assert!(matrix4::D.add_m(&matrix4::D.transpose()).is_symmetric());
+}
+
+#[test]
+fn test_fixed_array_round_trip() {
+    let m: Matrix2<f64> = FixedArray::from_fixed([[1.0f64, 3.0], [2.0, 4.0]]);
+    assert_eq!(m, matrix2::A);
+    assert_eq!(m.into_fixed(), [[1.0f64, 3.0], [2.0, 4.0]]);
+
+    let m: Matrix3<f64> = FixedArray::from_fixed([[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(m.row(0), Vector3::new(1.0f64, 4.0, 7.0));
+    assert_eq!(m.into_fixed(), [[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+    let m: Matrix4<f64> = FixedArray::from_fixed(matrix4::A.into_fixed());
+    assert_eq!(m, matrix4::A);
+}
+
+#[test]
+fn test_as_slice() {
+    assert_eq!(matrix2::A.as_slice(), [1.0f64, 3.0, 2.0, 4.0].as_slice());
+    assert_eq!(matrix3::A.as_slice().len(), 9);
+    assert_eq!(matrix3::A.as_slice()[3], matrix3::A[1][0]);
+
+    let s = matrix4::A.as_slice();
+    assert_eq!(s.len(), 16);
+    for c in 0..4 {
+        for r in 0..4 {
+            assert_eq!(s[c * 4 + r], matrix4::A[c][r]);
+        }
+    }
+
+    let mut m = matrix4::A;
+    m.as_mut_slice()[15] = 0.0;
+    assert_eq!(m[3][3], 0.0);
+}
+
+#[test]
+fn test_from_cols() {
+    assert_eq!(Matrix2::from_cols(Vector2::unit_x(), Vector2::unit_y()), Matrix2::<f64>::identity());
+    assert_eq!(Matrix3::from_cols(Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()),
+               Matrix3::<f64>::identity());
+    assert_eq!(Matrix4::from_cols(Vector4::unit_x(), Vector4::unit_y(), Vector4::unit_z(), Vector4::unit_w()),
+               Matrix4::<f64>::identity());
+
+    // The columns are the images of the basis vectors.
+    let (x, y, z) = (Vector3::new(0.0f64, 1.0, 0.0), Vector3::new(-1.0f64, 0.0, 0.0), Vector3::new(0.0f64, 0.0, 2.0));
+    let m = Matrix3::from_cols(x, y, z);
+    assert_eq!(m.mul_v(&Vector3::unit_x()), x);
+    assert_eq!(m.mul_v(&Vector3::unit_y()), y);
+    assert_eq!(m.mul_v(&Vector3::unit_z()), z);
+    assert_eq!(m, Matrix3::new(0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 2.0));
+}
+
+#[test]
+fn test_decompose() {
+    fn compose(scale: &Vector3<f64>, rot: &Quaternion<f64>, disp: &Vector3<f64>) -> Matrix4<f64> {
+        Matrix4::from_translation(disp).mul_m(&rot.to_matrix4())
+                                       .mul_m(&Matrix4::from_diagonal(&scale.extend(1.0)))
+    }
+
+    let axis = Vector3::new(1.0f64, 1.0, 0.0).normalize();
+    let rot: Quaternion<f64> = Rotation3::from_axis_angle(&axis, rad(0.7f64));
+    let scale = Vector3::new(2.0f64, 3.0, 0.5);
+    let disp = Vector3::new(1.0f64, -2.0, 3.0);
+
+    let (s, r, t) = compose(&scale, &rot, &disp).decompose().unwrap();
+    assert!(s.approx_eq(&scale));
+    assert!(r.approx_eq(&rot));
+    assert!(t.approx_eq(&disp));
+
+    // A mirrored transform comes back with a negative x scale.
+    let mirrored = compose(&Vector3::new(-2.0f64, 3.0, 0.5), &rot, &disp);
+    let (s, r, t) = mirrored.decompose().unwrap();
+    assert!(s.approx_eq(&Vector3::new(-2.0, 3.0, 0.5)));
+    assert!(r.approx_eq(&rot));
+    assert!(compose(&s, &r, &t).approx_eq(&mirrored));
+
+    // Mirroring along another axis still round trips, with the rotation
+    // taking up the difference.
+    let mirrored = compose(&Vector3::new(2.0f64, -3.0, 0.5), &rot, &disp);
+    let (s, r, t) = mirrored.decompose().unwrap();
+    assert!(compose(&s, &r, &t).approx_eq(&mirrored));
+
+    // Projections, shears and degenerate scales can't be decomposed.
+    assert!(perspective(deg(60.0f64), 1.0, 0.1, 10.0).decompose().is_none());
+    let shear = Matrix4::new(1.0f64, 0.0, 0.0, 0.0,
+                             0.5, 1.0, 0.0, 0.0,
+                             0.0, 0.0, 1.0, 0.0,
+                             0.0, 0.0, 0.0, 1.0);
+    assert!(shear.decompose().is_none());
+    assert!(Matrix4::from_diagonal(&Vector4::new(1.0f64, 0.0, 1.0, 1.0)).decompose().is_none());
+}
+
+#[test]
+fn test_cast() {
+    let m = matrix4::A.cast::<f32>().unwrap();
+    assert_eq!(m.row(1), Vector4::new(5.0f32, 6.0, 7.0, 8.0));
+    assert!(Matrix3::new(1i32, 2, 3, 4, 5, 6, 7, 8, 256).cast::<u8>().is_none());
+    assert_eq!(matrix2::A.cast::<i32>().unwrap().x, Vector2::new(1i32, 3));
+}
+
+#[test]
+fn test_look_at_handedness() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(1.0f64, 2.0, -7.0);
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let ahead = Point3::new(1.0f64, 2.0, -2.0).to_homogeneous();
+    let above = Point3::new(1.0f64, 4.0, 3.0).to_homogeneous();
+    let right = Point3::new(2.0f64, 2.0, 3.0).to_homogeneous();
+
+    let rh = Matrix4::look_at_rh(&eye, &center, &up);
+    assert_eq!(rh, Matrix4::look_at(&eye, &center, &up));
+    assert!(rh.mul_v(&ahead).approx_eq(&Vector4::new(0.0, 0.0, -5.0, 1.0)));
+    assert!(rh.mul_v(&above).approx_eq(&Vector4::new(0.0, 2.0, 0.0, 1.0)));
+    assert!(rh.mul_v(&right).approx_eq(&Vector4::new(1.0, 0.0, 0.0, 1.0)));
+
+    let lh = Matrix4::look_at_lh(&eye, &center, &up);
+    assert!(lh.mul_v(&ahead).approx_eq(&Vector4::new(0.0, 0.0, 5.0, 1.0)));
+    assert!(lh.mul_v(&above).approx_eq(&Vector4::new(0.0, 2.0, 0.0, 1.0)));
+    // Keeping the same world-space setup mirrors the view horizontally.
+    assert!(lh.mul_v(&right).approx_eq(&Vector4::new(-1.0, 0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn test_is_finite_is_nan() {
+    assert!(matrix4::A.is_finite());
+    assert!(!matrix4::A.is_nan());
+
+    let mut m = matrix3::A;
+    m[2][1] = f64::INFINITY;
+    assert!(!m.is_finite());
+    assert!(!m.is_nan());
+    m[0][0] = f64::NAN;
+    assert!(m.is_nan());
+
+    let nan = Matrix2::new(1.0f64, 0.0, f64::NAN, 1.0);
+    assert!(nan.is_nan());
+    assert!(!nan.is_finite());
+}
+
+#[test]
+fn test_invert_near_singular() {
+    let tiny = Matrix4::from_diagonal(&Vector4::new(1.0e-5f64, 1.0e-5, 1.0e-5, 1.0e-5));
+    assert!(tiny.determinant() < 1.0e-19);
+    assert!(tiny.invert().is_none());
+    assert!(Matrix3::from_diagonal(&Vector3::new(1.0f64, 1.0e-10, 1.0e-10)).invert().is_none());
+
+    let mut nan = Matrix4::<f64>::identity();
+    nan[1][2] = f64::NAN;
+    assert!(nan.invert().is_none());
+
+    let mut inf = Matrix3::<f64>::identity();
+    inf[0][0] = f64::INFINITY;
+    assert!(inf.invert().is_none());
+    assert!(Matrix2::new(f64::NAN, 0.0, 0.0, 1.0).invert().is_none());
+
+    // Matrices that are merely small remain invertible
+    assert!(Matrix2::from_diagonal(&Vector2::new(0.01f64, 0.01)).invert().is_some());
+}
+
+#[test]
+fn test_pow() {
+    let m = matrix3::A.div_s(10.0);
+    let manual = m.mul_m(&m).mul_m(&m).mul_m(&m).mul_m(&m);
+    assert!(m.pow(5).approx_eq(&manual));
+    assert_eq!(m.pow(1), m);
+    assert!(m.pow(0).is_identity());
+
+    let fib = Matrix2::new(1.0f64, 1.0, 1.0, 0.0);
+    assert_eq!(fib.pow(10), Matrix2::new(89.0f64, 55.0, 55.0, 34.0));
+    assert_eq!(matrix4::A.pow(2), matrix4::A.mul_m(&matrix4::A));
+    assert!(Matrix4::<f64>::identity().pow(7).is_identity());
+}
+
+#[test]
+fn test_in_place_ops() {
+    let a = matrix3::A;
+    let b = matrix3::B;
+
+    let mut m = a; m.add_self_m(&b); assert_eq!(m, a.add_m(&b));
+    let mut m = a; m.sub_self_m(&b); assert_eq!(m, a.sub_m(&b));
+    let mut m = a; m.mul_self_m(&b); assert_eq!(m, a.mul_m(&b));
+    let mut m = a; m.mul_self_s(2.0); assert_eq!(m, a.mul_s(2.0));
+    let mut m = a; m.div_self_s(2.0); assert_eq!(m, a.div_s(2.0));
+    let mut m = a; m.rem_self_s(2.0); assert_eq!(m, a.rem_s(2.0));
+    let mut m = a; m.neg_self(); assert_eq!(m, -a);
+    let mut m = a; m.transpose_self(); assert_eq!(m, a.transpose());
+}
+
+#[test]
+fn test_operators() {
+    assert_eq!(matrix3::A * matrix3::V, matrix3::A.mul_v(&matrix3::V));
+    assert_eq!(matrix4::A * matrix4::V, matrix4::A.mul_v(&matrix4::V));
+    assert_eq!(matrix2::A * matrix2::V, matrix2::A.mul_v(&matrix2::V));
+    assert_eq!(matrix3::A * matrix3::B, matrix3::A.mul_m(&matrix3::B));
+    assert_eq!(matrix4::A * 2.0, matrix4::A.mul_s(2.0));
+    assert_eq!(matrix3::A + matrix3::B, matrix3::A.add_m(&matrix3::B));
+    assert_eq!(-matrix2::A, matrix2::A.mul_s(-1.0));
+}
+
+#[test]
+fn test_index_columns() {
+    let mut m = matrix4::A;
+    assert_eq!(m[2], m.z);
+    assert_eq!(m[2][1], m.z.y);
+
+    m[2] = Vector4::new(1.0f64, 2.0, 3.0, 4.0);
+    assert_eq!(m.z, Vector4::new(1.0f64, 2.0, 3.0, 4.0));
+    m[2][1] = 7.0;
+    assert_eq!(m.z.y, 7.0);
+    assert_eq!(m.x, matrix4::A.x);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_range() {
+    matrix3::A[3];
+}
+
+#[test]
+fn test_default() {
+    use std::default::Default;
+
+    let m4: Matrix4<f64> = Default::default();
+    let m3: Matrix3<f32> = Default::default();
+    let m2: Matrix2<i32> = Default::default();
+    assert_eq!(m4, Matrix4::identity());
+    assert_eq!(m3, Matrix3::identity());
+    assert_eq!(m2, Matrix2::identity());
+}
+
+#[test]
+fn test_iter() {
+    let elems: Vec<f64> = matrix2::A.iter().cloned().collect();
+    assert_eq!(elems, vec![matrix2::A.x.x, matrix2::A.x.y, matrix2::A.y.x, matrix2::A.y.y]);
+    assert_eq!(matrix3::A.iter().count(), 9);
+    assert_eq!(matrix4::A.iter().count(), 16);
+
+    let mut m = matrix4::A;
+    for x in m.iter_mut() {
+        *x = -*x;
+    }
+    assert_eq!(m, -matrix4::A);
+}
+
+#[test]
+fn test_scale_translation_constructors() {
+    let p = Point3::new(1.0f64, -2.0, 3.0);
+    let apply = |m: Matrix4<f64>| Point3::from_homogeneous(&m.mul_v(&p.to_homogeneous()));
+
+    assert_eq!(apply(Matrix4::from_translation(&Vector3::new(1.0, 2.0, 3.0))), Point3::new(2.0, 0.0, 6.0));
+    assert_eq!(apply(Matrix4::from_scale(2.0)), Point3::new(2.0, -4.0, 6.0));
+    assert_eq!(apply(Matrix4::from_nonuniform_scale(&Vector3::new(2.0, 0.5, -1.0))), Point3::new(2.0, -1.0, -3.0));
+
+    let p2 = Vector3::new(1.0f64, -2.0, 1.0);
+    assert_eq!(Matrix3::from_translation(&Vector2::new(3.0f64, 4.0)).mul_v(&p2), Vector3::new(4.0f64, 2.0, 1.0));
+    // Directions, with a zero homogeneous coordinate, are unaffected
+    assert_eq!(Matrix3::from_translation(&Vector2::new(3.0f64, 4.0)).mul_v(&Vector3::new(1.0, 0.0, 0.0)),
+               Vector3::new(1.0f64, 0.0, 0.0));
+}
+
+#[test]
+fn test_from_angle_axes() {
+    let quarter = rad(f64::consts::FRAC_PI_2);
+    let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::<f64>::unit_z());
+
+    assert!(Matrix3::from_angle_x(quarter).mul_v(&y).approx_eq(&z));
+    assert!(Matrix3::from_angle_y(quarter).mul_v(&z).approx_eq(&x));
+    assert!(Matrix3::from_angle_z(quarter).mul_v(&x).approx_eq(&y));
+    assert!(Matrix3::from_angle_x(quarter).mul_v(&x).approx_eq(&x));
+
+    assert!(Matrix4::from_angle_x(quarter).mul_v(&y.extend(1.0)).approx_eq(&z.extend(1.0)));
+    assert!(Matrix4::from_angle_y(quarter).mul_v(&z.extend(1.0)).approx_eq(&x.extend(1.0)));
+    assert!(Matrix4::from_angle_z(quarter).mul_v(&x.extend(0.0)).approx_eq(&y.extend(0.0)));
+}
+
+#[test]
+fn test_from_axis_angle_matches_quaternion() {
+    let axes = [Vector3::new(1.0f64, 0.0, 0.0), Vector3::new(0.0f64, -1.0, 0.0),
+                Vector3::new(1.0f64, 2.0, 3.0).normalize(), Vector3::new(-0.3f64, 0.4, -1.0).normalize()];
+    for axis in axes.iter() {
+        for &angle in [0.0f64, 0.25, 1.5, -2.0, 3.1].iter() {
+            let q: Quaternion<f64> = Rotation3::from_axis_angle(axis, rad(angle));
+            assert!(Matrix3::from_axis_angle(axis, rad(angle)).approx_eq(&q.to_matrix3()));
+        }
+    }
+}
+
+#[test]
+fn test_shear() {
+    // The top right corner of the unit square
+    let corner = Vector3::new(1.0f64, 1.0, 1.0);
+    assert_eq!(Matrix3::from_shear_2d(0.5f64, 0.0).mul_v(&corner), Vector3::new(1.5f64, 1.0, 1.0));
+    assert_eq!(Matrix3::from_shear_2d(0.0f64, -2.0).mul_v(&corner), Vector3::new(1.0f64, -1.0, 1.0));
+    assert_eq!(Matrix3::from_shear_2d(0.5f64, 0.25).mul_v(&Vector3::new(0.0f64, 0.0, 1.0)), Vector3::new(0.0f64, 0.0, 1.0));
+
+    let shear = Matrix4::from_shear(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(shear.mul_v(&Vector4::new(1.0f64, 0.0, 0.0, 1.0)), Vector4::new(1.0f64, 3.0, 5.0, 1.0));
+    assert_eq!(shear.mul_v(&Vector4::new(0.0f64, 1.0, 0.0, 1.0)), Vector4::new(1.0f64, 1.0, 6.0, 1.0));
+    assert_eq!(shear.mul_v(&Vector4::new(0.0f64, 0.0, 1.0, 1.0)), Vector4::new(2.0f64, 4.0, 1.0, 1.0));
+    assert!(shear.decompose().is_none());
+}
+
+#[test]
+fn test_matrix2_invert_round_trip() {
+    let m = Matrix2::new(4.0f64, -2.0f64,
+                         3.0f64,  1.0f64);
+    assert_eq!(m.determinant(), 10.0f64);
+
+    let inv = m.invert().unwrap();
+    assert!(m.mul_m(&inv).approx_eq(&Matrix2::identity()));
+    assert!(inv.mul_m(&m).approx_eq(&Matrix2::identity()));
+    assert!(inv.determinant().approx_eq(&0.1f64));
+
+    // a zero column makes the determinant zero
+    let singular = Matrix2::new(0.0f64, 0.0f64,
+                                3.0f64, 1.0f64);
+    assert_eq!(singular.determinant(), 0.0f64);
+    assert!(singular.invert().is_none());
+}
+
+#[test]
+fn test_solve() {
+    // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3 has the solution
+    // (2, 3, -1). The first column needs pivoting, as |-3| > |2|.
+    let a = Matrix3::new( 2.0f64, -3.0f64, -2.0f64,
+                          1.0f64, -1.0f64,  1.0f64,
+                         -1.0f64,  2.0f64,  2.0f64);
+    let x = a.solve(&Vector3::new(8.0, -11.0, -3.0)).unwrap();
+    assert!(x.approx_eq(&Vector3::new(2.0, 3.0, -1.0)));
+
+    // a zero in the top-left corner can only be handled by swapping rows
+    let b = Matrix4::new(0.0f64, 1.0f64, 0.0f64, 0.0f64,
+                         2.0f64, 0.0f64, 0.0f64, 0.0f64,
+                         0.0f64, 0.0f64, 0.0f64, 4.0f64,
+                         0.0f64, 0.0f64, 3.0f64, 0.0f64);
+    let x = b.solve(&Vector4::new(2.0, 1.0, 6.0, 8.0)).unwrap();
+    assert!(x.approx_eq(&Vector4::new(1.0, 1.0, 2.0, 2.0)));
+    assert!(b.mul_v(&x).approx_eq(&Vector4::new(2.0, 1.0, 6.0, 8.0)));
+
+    let c = Matrix2::new(1.0f64, 3.0f64,
+                         2.0f64, 4.0f64);
+    assert!(c.solve(&Vector2::new(5.0, 13.0)).unwrap().approx_eq(&Vector2::new(3.0, 1.0)));
+
+    assert!(matrix3::A.solve(&Vector3::new(1.0, 2.0, 3.0)).is_none());
+    assert!(matrix4::A.solve(&Vector4::new(1.0, 2.0, 3.0, 4.0)).is_none());
+}
+
+#[test]
+fn test_symmetric_eigen() {
+    // a diagonal matrix is already decomposed
+    let d = Matrix3::new(3.0f64, 0.0, 0.0,
+                         0.0, -1.0, 0.0,
+                         0.0, 0.0, 2.0);
+    let (values, vectors) = d.symmetric_eigen();
+    assert_eq!(values, Vector3::new(3.0, -1.0, 2.0));
+    assert!(vectors.is_identity());
+
+    // rotate the diagonal matrix, and check that the decomposition puts it
+    // back together, with each pair satisfying `m * v = λ * v`
+    let r = Matrix3::from_euler(rad(0.4f64), rad(-1.3f64), rad(2.1f64));
+    let m = r.mul_m(&d).mul_m(&r.transpose());
+    let (values, vectors) = m.symmetric_eigen();
+    assert!(vectors.mul_m(&vectors.transpose()).approx_eq(&Matrix3::identity()));
+    assert!(vectors.mul_m(&Matrix3::from_diagonal(&values)).mul_m(&vectors.transpose()).approx_eq(&m));
+    for i in 0..3 {
+        assert!(m.mul_v(&vectors[i]).approx_eq(&vectors[i].mul_s(values[i])));
+    }
+    let mut sorted = [values.x, values.y, values.z];
+    sorted.sort_by(|a, b| std::cmp::PartialOrd::partial_cmp(a, b).unwrap());
+    assert!(Vector3::new(sorted[0], sorted[1], sorted[2]).approx_eq(&Vector3::new(-1.0, 2.0, 3.0)));
+
+    // nearly equal eigenvalues still give orthonormal eigenvectors
+    let n = r.mul_m(&Matrix3::from_diagonal(&Vector3::new(1.0f64, 1.0 + 1.0e-12, 5.0)))
+             .mul_m(&r.transpose());
+    let (values, vectors) = n.symmetric_eigen();
+    assert!(vectors.mul_m(&vectors.transpose()).approx_eq(&Matrix3::identity()));
+    assert!(vectors.mul_m(&Matrix3::from_diagonal(&values)).mul_m(&vectors.transpose()).approx_eq(&n));
+}
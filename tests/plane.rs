@@ -23,7 +23,7 @@ fn test_from_points() {
     assert_eq!(Plane::from_points(Point3::new(5.0f64, 0.0f64,  5.0f64),
                                   Point3::new(5.0f64, 5.0f64,  5.0f64),
                                   Point3::new(5.0f64, 0.0f64, -1.0f64)),
-    	Some(Plane::from_abcd(-1.0f64, 0.0f64, 0.0f64, 5.0f64)));
+    	Some(Plane::from_abcd(-1.0f64, 0.0f64, 0.0f64, -5.0f64)));
 
     assert_eq!(Plane::from_points(Point3::new(0.0f64, 5.0f64, -5.0f64),
                                   Point3::new(0.0f64, 5.0f64,  0.0f64),
@@ -33,7 +33,7 @@ fn test_from_points() {
 
 #[test]
 fn test_ray_intersection() {
-    let p0 = Plane::from_abcd(1f64, 0f64, 0f64, -7f64);
+    let p0 = Plane::from_abcd(1f64, 0f64, 0f64, 7f64);
     let r0: Ray3<f64> = Ray::new(Point3::new(2f64, 3f64, 4f64), Vector3::new(1f64, 1f64, 1f64).normalize());
     assert_eq!((p0, r0).intersection(), Some(Point3::new(7f64, 8f64, 9f64)));
 
@@ -42,4 +42,67 @@ fn test_ray_intersection() {
                                 Point3::new(5f64, 0f64, -1f64)).unwrap();
     let r1: Ray3<f64> = Ray::new(Point3::new(0f64, 0f64, 0f64), Vector3::new(-1f64, 0f64, 0f64).normalize());
     assert_eq!((p1, r1).intersection(), None); // r1 points away from p1
+
+    let p2 = Plane::from_abcd(0f64, 1f64, 0f64, 2f64);
+    let r2: Ray3<f64> = Ray::new(Point3::new(1f64, 5f64, 1f64), Vector3::new(0f64, -2f64, 0f64));
+    assert_eq!(p2.intersection_ray(&r2), Some(Point3::new(1f64, 2f64, 1f64)));
+
+    let r3: Ray3<f64> = Ray::new(Point3::new(1f64, 5f64, 1f64), Vector3::new(1f64, 0f64, 0f64));
+    assert_eq!(p2.intersection_ray(&r3), None); // r3 is parallel to p2
+}
+
+#[test]
+fn test_distance_to_point() {
+    let p = Plane::from_point_normal(Point3::new(0f64, 0f64, 3f64), Vector3::new(0f64, 0f64, 1f64));
+    assert_eq!(p.distance_to_point(&Point3::new(1f64, 2f64, 5f64)), 2f64);
+    assert_eq!(p.distance_to_point(&Point3::new(1f64, 2f64, -1f64)), -4f64);
+    assert_eq!(p.distance_to_point(&Point3::new(1f64, 2f64, 3f64)), 0f64);
+
+    let p = Plane::from_points(Point3::new(0f64, 0f64, 3f64),
+                               Point3::new(1f64, 0f64, 3f64),
+                               Point3::new(0f64, 1f64, 3f64)).unwrap();
+    assert_eq!(p.distance_to_point(&Point3::new(1f64, 2f64, 5f64)), 2f64);
+}
+
+#[test]
+fn test_plane_intersection() {
+    let p0 = Plane::from_abcd(1f64, 0f64, 0f64, 1f64);
+    let p1 = Plane::from_abcd(0f64, 1f64, 0f64, 2f64);
+    let ray = (p0, p1).intersection().unwrap();
+    assert!(ray.origin.approx_eq(&Point3::new(1f64, 2f64, 0f64)));
+    assert!(ray.direction.approx_eq(&Vector3::new(0f64, 0f64, 1f64)));
+
+    let p2 = Plane::from_abcd(-1f64, 0f64, 0f64, 3f64);
+    assert!(p0.intersection_plane(&p2).is_none()); // p0 and p2 are parallel
+}
+
+#[test]
+fn test_three_plane_intersection() {
+    let x = Plane::from_abcd(1f64, 0f64, 0f64, 0f64);
+    let y = Plane::from_abcd(0f64, 1f64, 0f64, 0f64);
+    let z = Plane::from_abcd(0f64, 0f64, 1f64, 0f64);
+    assert_eq!((x, y, z).intersection(), Some(Point3::new(0f64, 0f64, 0f64)));
+
+    let a = Plane::from_abcd(1f64, 0f64, 0f64, 1f64);
+    let b = Plane::from_abcd(0f64, 1f64, 0f64, 2f64);
+    let c = Plane::from_point_normal(Point3::new(0f64, 0f64, 3f64),
+                                     Vector3::new(1f64, 1f64, 1f64).normalize());
+    let p = Plane::intersection_3(&a, &b, &c).unwrap();
+    assert!(p.approx_eq(&Point3::new(1f64, 2f64, 0f64)));
+
+    let d = Plane::from_abcd(0f64, 0f64, 1f64, 1f64);
+    let e = Plane::from_abcd(0f64, 0f64, 1f64, 4f64);
+    assert_eq!(Plane::intersection_3(&a, &d, &e), None); // d and e are parallel
+}
+
+#[test]
+fn test_project_reflect_point() {
+    let ground = Plane::from_abcd(0f64, 1f64, 0f64, 0f64);
+    let p = Point3::new(1f64, 3f64, -2f64);
+    assert_eq!(ground.project_point(&p), Point3::new(1f64, 0f64, -2f64));
+    assert_eq!(ground.reflect_point(&p), Point3::new(1f64, -3f64, -2f64));
+
+    let raised = Plane::from_abcd(0f64, 1f64, 0f64, 1f64);
+    assert_eq!(raised.project_point(&p), Point3::new(1f64, 1f64, -2f64));
+    assert_eq!(raised.reflect_point(&p), Point3::new(1f64, -1f64, -2f64));
 }
@@ -16,9 +16,10 @@
 
 extern crate cgmath;
 
-use cgmath::{Point, Point3, Vector, Vector3};
+use cgmath::{Point, Point2, Point3, Vector, Vector3, EuclideanVector};
 use cgmath::{Bound, Relation, Plane};
-use cgmath::{ApproxEq};
+use cgmath::{ApproxEq, FixedArray};
+use cgmath::centroid;
 
 #[test]
 fn test_homogeneous() {
@@ -36,3 +37,146 @@ fn test_bound() {
     assert_eq!(point.add_v(&normal).relate_plane(&plane), Relation::In);
     assert_eq!(point.add_v(&normal.mul_s(-1.0)).relate_plane(&plane), Relation::Out);
 }
+
+#[test]
+fn test_distance() {
+    let a = Point3::new(1.0f64, 2.0, 3.0);
+    let b = Point3::new(-2.0f64, 6.0, 3.0);
+    assert_eq!(a.distance2(&b), 25.0);
+    assert_eq!(a.distance(&b), 5.0);
+    assert!(a.distance(&b).approx_eq(&a.sub_p(&b).length()));
+    assert!(a.distance2(&b).approx_eq(&a.sub_p(&b).length2()));
+    assert_eq!(a.distance(&a), 0.0);
+
+    let c = Point2::new(1i32, 1);
+    let d = Point2::new(4i32, 5);
+    assert_eq!(c.distance2(&d), 25);
+}
+
+#[test]
+fn test_fixed_array_round_trip() {
+    let p: Point2<f32> = FixedArray::from_fixed([1.0f32, 2.0]);
+    assert_eq!(p, Point2::new(1.0f32, 2.0));
+    assert_eq!(p.into_fixed(), [1.0f32, 2.0]);
+
+    let p: Point3<f32> = FixedArray::from_fixed([1.0f32, 2.0, 3.0]);
+    assert_eq!(p, Point3::new(1.0f32, 2.0, 3.0));
+    assert_eq!(p.into_fixed(), [1.0f32, 2.0, 3.0]);
+}
+
+#[test]
+fn test_as_slice() {
+    let mut p = Point3::new(1.0f32, 2.0, 3.0);
+    assert_eq!(p.as_slice(), [1.0f32, 2.0, 3.0].as_slice());
+    p.as_mut_slice()[2] = 7.0;
+    assert_eq!(p, Point3::new(1.0f32, 2.0, 7.0));
+    assert_eq!(Point2::new(1.0f32, 2.0).as_slice().len(), 2);
+}
+
+#[test]
+fn test_cast() {
+    let p = Point3::new(1.5f64, -2.25, 3.0).cast::<f32>().unwrap();
+    assert!(p.approx_eq(&Point3::new(1.5f32, -2.25, 3.0)));
+    assert_eq!(Point2::new(-1i32, 1).cast::<u32>(), None);
+}
+
+#[test]
+fn test_hash_set() {
+    use std::collections::HashSet;
+
+    let mut visited = HashSet::new();
+    assert!(visited.insert(Point3::new(1i32, 2, 3)));
+    assert!(visited.insert(Point3::new(3i32, 2, 1)));
+    assert!(!visited.insert(Point3::new(1i32, 2, 3)));
+    assert!(visited.contains(&Point3::new(3i32, 2, 1)));
+}
+
+#[test]
+fn test_sort() {
+    let mut points = vec![Point2::new(2i32, 1), Point2::new(-1i32, 5), Point2::new(2i32, -3), Point2::new(-1i32, 0)];
+    points.sort();
+    assert_eq!(points, vec![Point2::new(-1i32, 0), Point2::new(-1i32, 5), Point2::new(2i32, -3), Point2::new(2i32, 1)]);
+
+    assert!(Point3::new(1.0f64, 2.0, 3.0) < Point3::new(1.0f64, 2.5, 0.0));
+}
+
+#[test]
+fn test_is_finite_is_nan() {
+    use std::f64;
+
+    assert!(Point2::new(1.0f64, 2.0).is_finite());
+    assert!(Point3::new(1.0f64, f64::NAN, 2.0).is_nan());
+    assert!(!Point3::new(f64::INFINITY, 0.0, 2.0).is_finite());
+    assert!(!Point3::new(f64::INFINITY, 0.0, 2.0).is_nan());
+}
+
+#[test]
+fn test_in_place_ops() {
+    let p = Point3::new(1.0f64, -2.0, 4.0);
+    let v = Vector3::new(0.5f64, 1.0, -3.0);
+
+    let mut q = p; q.add_self_v(&v); assert_eq!(q, p.add_v(&v));
+    let mut q = p; q.sub_self_v(&v); assert_eq!(q, p.sub_v(&v));
+    let mut q = p; q.mul_self_s(2.0); assert_eq!(q, p.mul_s(2.0));
+    let mut q = p; q.div_self_s(2.0); assert_eq!(q, p.div_s(2.0));
+    let mut q = p; q.rem_self_s(3.0); assert_eq!(q, p.rem_s(3.0));
+
+    assert_eq!(p.sub_v(&v).add_v(&v), p);
+    assert_eq!(Point2::new(3i32, 4).sub_v(&cgmath::Vector2::new(1i32, 1)), Point2::new(2i32, 3));
+}
+
+#[test]
+fn test_operators() {
+    let p = Point3::new(1.0f64, -2.0, 4.0);
+    let q = Point3::new(0.0f64, 3.0, 1.0);
+    let v = Vector3::new(0.5f64, 1.0, -3.0);
+
+    assert_eq!(p + v, p.add_v(&v));
+    assert_eq!(p - v, p.sub_v(&v));
+    assert_eq!(p - q, p.sub_p(&q));
+    assert_eq!(p * 2.0, p.mul_s(2.0));
+    assert_eq!(Point2::new(1i32, 2) + cgmath::Vector2::new(3i32, 4), Point2::new(4i32, 6));
+}
+
+#[test]
+fn test_default() {
+    use std::default::Default;
+
+    let p3: Point3<f64> = Default::default();
+    let p2: Point2<i32> = Default::default();
+    assert_eq!(p3, Point::origin());
+    assert_eq!(p2, Point2::new(0i32, 0));
+}
+
+#[test]
+fn test_from_iter() {
+    let p: Point3<f64> = [1.0f64, 2.0, 3.0].iter().cloned().collect();
+    assert_eq!(p, Point3::new(1.0f64, 2.0, 3.0));
+    let p: Point2<i32> = vec![4i32, 5, 6].into_iter().collect();
+    assert_eq!(p, Point2::new(4i32, 5));
+}
+
+#[test]
+fn test_midpoint() {
+    let a = Point3::new(1.0f64, -2.0, 4.0);
+    let b = Point3::new(3.0f64, 6.0, -4.0);
+    assert_eq!(a.midpoint(&b), Point3::new(2.0, 2.0, 0.0));
+    assert_eq!(b.midpoint(&a), Point3::new(2.0, 2.0, 0.0));
+    assert_eq!(Point2::new(-1isize, 5).midpoint(&Point2::new(3, 1)), Point2::new(1, 3));
+}
+
+#[test]
+fn test_centroid() {
+    let triangle = [Point2::new(0.0f64, 0.0),
+                    Point2::new(6.0f64, 0.0),
+                    Point2::new(0.0f64, 3.0)];
+    assert!(centroid(&triangle).approx_eq(&Point2::new(2.0, 1.0)));
+    assert_eq!(centroid(&[Point3::new(1.0f64, 2.0, 3.0)]), Point3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+#[should_panic]
+fn test_centroid_empty() {
+    let points: [Point3<f64>; 0] = [];
+    centroid(&points);
+}
@@ -15,7 +15,13 @@
 
 extern crate cgmath;
 
+use std::num::Float;
+
 use cgmath::{Vector4, ortho, Matrix, Matrix4, Vector};
+use cgmath::{perspective, perspective_infinite, frustum, deg, rad, ApproxEq};
+use cgmath::{perspective_reverse_z, ortho_reverse_z, ortho_symmetric};
+use cgmath::{perspective_no, perspective_zo, ortho_no, ortho_zo};
+use cgmath::{project, unproject, unproject_ray, Point, Point2, Point3, Vector3};
 
 #[test]
 fn test_ortho_scale() {
@@ -67,3 +73,143 @@ fn test_ortho_translate() {
     let orig = o.mul_v(&vec_orig);
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_perspective_matches_frustum() {
+    // a 90° field of view puts the top of the near plane at the same distance
+    // as the near plane itself
+    let p: Matrix4<f64> = perspective(deg(90.0f64), 2.0, 0.5, 100.0);
+    let f: Matrix4<f64> = frustum(-1.0, 1.0, -0.5, 0.5, 0.5, 100.0);
+    assert!(p.approx_eq(&f));
+
+    let fovy = 1.2f64;
+    let top = 0.1 * (fovy / 2.0).tan();
+    let p: Matrix4<f64> = perspective(rad(fovy), 1.5, 0.1, 50.0);
+    let f: Matrix4<f64> = frustum(-top * 1.5, top * 1.5, -top, top, 0.1, 50.0);
+    assert!(p.approx_eq(&f));
+}
+
+#[test]
+fn test_perspective_infinite() {
+    let p: Matrix4<f64> = perspective_infinite(deg(60.0f64), 1.5, 0.1);
+    let finite: Matrix4<f64> = perspective(deg(60.0f64), 1.5, 0.1, 1e12);
+    assert!(p.approx_eq(&finite));
+
+    let depth = |z: f64| { let v = p.mul_v(&Vector4::new(0.0, 0.0, z, 1.0)); v.z / v.w };
+    assert!(depth(-0.1).approx_eq(&-1.0));
+    assert!(depth(-1e3) < 1.0);
+    assert!(depth(-1e30).approx_eq(&1.0));
+    assert!(depth(-1e300).is_finite());
+    assert!(depth(-1e3) < depth(-1e6));
+}
+
+#[test]
+fn test_reverse_z() {
+    fn depth(m: &Matrix4<f64>, z: f64) -> f64 {
+        let v = m.mul_v(&Vector4::new(0.0, 0.0, z, 1.0));
+        v.z / v.w
+    }
+
+    let p: Matrix4<f64> = perspective_reverse_z(deg(60.0f64), 1.5, 0.1, 100.0);
+    assert!(depth(&p, -0.1).approx_eq(&1.0));
+    assert!(depth(&p, -100.0).approx_eq(&0.0));
+    assert!(depth(&p, -1.0) > depth(&p, -10.0));
+
+    // x and y are unchanged from the usual projection
+    let q: Matrix4<f64> = perspective(deg(60.0f64), 1.5, 0.1, 100.0);
+    assert_eq!(p.x, q.x);
+    assert_eq!(p.y, q.y);
+
+    let o: Matrix4<f64> = ortho_reverse_z(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0);
+    assert!(depth(&o, -1.0).approx_eq(&1.0));
+    assert!(depth(&o, -5.0).approx_eq(&0.0));
+    assert!(depth(&o, -3.0).approx_eq(&0.5));
+    assert_eq!(o.mul_v(&Vector4::new(2.0, -1.0, -3.0, 1.0)), Vector4::new(1.0, -1.0, 0.5, 1.0));
+}
+
+#[test]
+fn test_ortho_symmetric() {
+    let o: Matrix4<f32> = ortho_symmetric(4., 2., 0.5, 10.);
+    assert_eq!(o, ortho(-2., 2., -1., 1., 0.5, 10.));
+
+    let o: Matrix4<f64> = ortho_symmetric(1920., 1080., -1., 1.);
+    assert_eq!(o, ortho(-960., 960., -540., 540., -1., 1.));
+    assert_eq!(o.mul_v(&Vector4::new(960., -540., 0., 1.)), Vector4::new(1., -1., 0., 1.));
+}
+
+#[test]
+fn test_depth_range() {
+    fn depth(m: &Matrix4<f64>, z: f64) -> f64 {
+        let v = m.mul_v(&Vector4::new(0.0, 0.0, z, 1.0));
+        v.z / v.w
+    }
+
+    let no: Matrix4<f64> = perspective_no(deg(60.0f64), 1.5, 0.1, 100.0);
+    assert_eq!(no, perspective(deg(60.0f64), 1.5, 0.1, 100.0));
+    assert!(depth(&no, -0.1).approx_eq(&-1.0));
+    assert!(depth(&no, -100.0).approx_eq(&1.0));
+
+    let zo: Matrix4<f64> = perspective_zo(deg(60.0f64), 1.5, 0.1, 100.0);
+    assert!(depth(&zo, -0.1).approx_eq(&0.0));
+    assert!(depth(&zo, -100.0).approx_eq(&1.0));
+    assert!(depth(&zo, -1.0) < depth(&zo, -10.0));
+    assert_eq!(zo.x, no.x);
+    assert_eq!(zo.y, no.y);
+
+    let no: Matrix4<f64> = ortho_no(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0);
+    assert_eq!(no, ortho(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0));
+    assert!(depth(&no, -1.0).approx_eq(&-1.0));
+    assert!(depth(&no, -5.0).approx_eq(&1.0));
+
+    let zo: Matrix4<f64> = ortho_zo(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0);
+    assert!(depth(&zo, -1.0).approx_eq(&0.0));
+    assert!(depth(&zo, -5.0).approx_eq(&1.0));
+    assert!(depth(&zo, -3.0).approx_eq(&0.5));
+    assert_eq!(zo.mul_v(&Vector4::new(2.0, -1.0, -3.0, 1.0)), Vector4::new(1.0, -1.0, 0.5, 1.0));
+}
+
+#[test]
+fn test_unproject() {
+    let proj = perspective(deg(90.0f64), 1.0, 1.0, 10.0);
+    let model = Matrix4::identity();
+    let viewport = (10.0f64, 20.0, 200.0, 200.0);
+
+    let centre = unproject(&Point3::new(110.0f64, 120.0, 0.0), &model, &proj, viewport).unwrap();
+    assert!(centre.approx_eq(&Point3::new(0.0f64, 0.0, -1.0)));
+    let corner = unproject(&Point3::new(210.0f64, 220.0, 0.0), &model, &proj, viewport).unwrap();
+    assert!(corner.approx_eq(&Point3::new(1.0f64, 1.0, -1.0)));
+    let far = unproject(&Point3::new(10.0f64, 120.0, 1.0), &model, &proj, viewport).unwrap();
+    assert!(far.approx_eq(&Point3::new(-10.0f64, 0.0, -10.0)));
+
+    // A camera moved back along z
+    let model = Matrix4::from_translation(&Vector3::new(0.0f64, 0.0, -5.0));
+    let ray = unproject_ray(&Point2::new(110.0f64, 120.0), &model, &proj, viewport).unwrap();
+    assert!(ray.origin.approx_eq(&Point3::new(0.0f64, 0.0, 4.0)));
+    assert!(ray.direction.approx_eq(&Vector3::new(0.0f64, 0.0, -1.0)));
+
+    let singular = Matrix4::from_diagonal(&Vector4::new(1.0f64, 1.0, 0.0, 1.0));
+    assert!(unproject(&Point3::new(0.0f64, 0.0, 0.5), &singular, &proj, viewport).is_none());
+    assert!(unproject_ray(&Point2::new(0.0f64, 0.0), &singular, &proj, viewport).is_none());
+}
+
+#[test]
+fn test_project() {
+    let proj = perspective(deg(60.0f64), 4.0 / 3.0, 0.5, 100.0);
+    let model = Matrix4::look_at(&Point3::new(3.0f64, 4.0, 5.0), &Point3::new(0.0, 0.0, 0.0), &Vector3::unit_y());
+    let viewport = (0.0f64, 0.0, 800.0, 600.0);
+
+    // The point looked at lands at the centre of the screen
+    let centre = project(&Point3::new(0.0f64, 0.0, 0.0), &model, &proj, viewport).unwrap();
+    assert!(Point2::new(centre.x, centre.y).approx_eq(&Point2::new(400.0f64, 300.0)));
+    assert!(centre.z > 0.0 && centre.z < 1.0);
+
+    // Projecting and unprojecting round trips
+    let points = [Point3::new(1.0f64, -2.0, 0.5), Point3::new(-0.5f64, 0.25, 2.0), Point3::new(0.0f64, 1.0, -1.0)];
+    for p in points.iter() {
+        let win = project(p, &model, &proj, viewport).unwrap();
+        assert!(unproject(&win, &model, &proj, viewport).unwrap().approx_eq_eps(p, &1.0e-9));
+    }
+
+    // A point in the plane of the camera has no projection
+    assert!(project(&Point3::new(3.0f64, 4.0, 5.0), &model, &proj, viewport).is_none());
+}
@@ -14,15 +14,18 @@
 // limitations under the License.
 
 
+#[macro_use]
 extern crate cgmath;
 
-use cgmath::{ToMatrix4, ToMatrix3};
-use cgmath::Quaternion;
+use cgmath::{ToMatrix4, ToMatrix3, ToQuaternion};
+use cgmath::{Quaternion, Vector3, Matrix, Matrix3, Point3};
 
 use cgmath::{Rad, rad, ApproxEq};
-use cgmath::Rotation3;
+use cgmath::{EuclideanVector, Rotation, Rotation3};
 
 use std::f32;
+use std::f64;
+use std::num::Float;
 
 #[test]
 fn to_matrix4()
@@ -84,3 +87,335 @@ fn to_and_from_quaternion()
     eq((rad(0f32), rad(-hpi), rad(1f32)), xzy_nhp.to_euler());
 
 }
+
+#[test]
+fn from_euler_axes() {
+    let a = rad(0.7f32);
+    let x: Quaternion<f32> = Rotation3::from_euler(a, rad(0f32), rad(0f32));
+    let y: Quaternion<f32> = Rotation3::from_euler(rad(0f32), a, rad(0f32));
+    let z: Quaternion<f32> = Rotation3::from_euler(rad(0f32), rad(0f32), a);
+    assert_approx_eq!(x, Rotation3::from_axis_angle(&Vector3::unit_x(), a));
+    assert_approx_eq!(y, Rotation3::from_axis_angle(&Vector3::unit_y(), a));
+    assert_approx_eq!(z, Rotation3::from_axis_angle(&Vector3::unit_z(), a));
+
+    // applied intrinsically as z, then y, then x
+    let xyz: Quaternion<f32> = Rotation3::from_euler(a, a, a);
+    assert_approx_eq!(xyz, z.mul_q(&y).mul_q(&x));
+}
+
+#[test]
+fn to_euler_poles() {
+    let hpi = f32::consts::FRAC_PI_2;
+    let v = Vector3::new(1f32, 2f32, 3f32);
+
+    for &pitch in [hpi, -hpi, hpi - 0.0001, -hpi + 0.0001].iter() {
+        for &(x, z) in [(0f32, 0f32), (0.5f32, 0f32), (0.3f32, -1.2f32)].iter() {
+            let q: Quaternion<f32> = Rotation3::from_euler(rad(x), rad(pitch), rad(z));
+            let (ex, ey, ez) = q.to_euler();
+            assert!(!ex.s.is_nan() && !ey.s.is_nan() && !ez.s.is_nan());
+            assert!(ey.s.approx_eq_eps(&pitch, &0.001));
+
+            // the angles describe the same rotation, even if they differ
+            let r: Quaternion<f32> = Rotation3::from_euler(ex, ey, ez);
+            assert!(r.rotate_vector(&v).approx_eq_eps(&q.rotate_vector(&v), &0.01));
+        }
+    }
+
+    // a quaternion that is not quite normalized must not produce NaNs
+    let q: Quaternion<f32> = Rotation3::from_euler(rad(0f32), rad(hpi), rad(0f32));
+    let (ex, ey, ez) = q.mul_s(1.001f32).to_euler();
+    assert!(!ex.s.is_nan() && !ey.s.is_nan() && !ez.s.is_nan());
+}
+
+#[test]
+fn slerp() {
+    let axis: Vector3<f32> = Vector3::unit_z();
+    let q0: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(0f32));
+    let q1: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(f32::consts::FRAC_PI_2));
+    let half: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(f32::consts::FRAC_PI_4));
+
+    assert!(q0.slerp(&q1, 0f32).approx_eq(&q0));
+    assert!(q0.slerp(&q1, 0.5f32).approx_eq(&half));
+    assert!(q0.slerp(&q1, 1f32).approx_eq(&q1));
+}
+
+#[test]
+fn slerp_shortest_path() {
+    let axis: Vector3<f32> = Vector3::unit_x();
+    let q0: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(0f32));
+    let q1: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(f32::consts::FRAC_PI_2));
+
+    // `-q1` is the same rotation as `q1`, so the result must be unchanged
+    assert!(q0.slerp(&-q1, 0.5f32).approx_eq(&q0.slerp(&q1, 0.5f32)));
+}
+
+#[test]
+fn nlerp() {
+    let axis: Vector3<f32> = Vector3::new(1f32, 1f32, 0f32).normalize();
+    let q0: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(0.3f32));
+    let q1: Quaternion<f32> = Rotation3::from_axis_angle(&axis, rad(2.5f32));
+
+    // the endpoints are the same as for slerp
+    assert!(q0.nlerp(&q1, 0f32).approx_eq(&q0.slerp(&q1, 0f32)));
+    assert!(q0.nlerp(&q1, 1f32).approx_eq(&q0.slerp(&q1, 1f32)));
+
+    // taking the shortest path, just like slerp
+    assert!(q0.nlerp(&-q1, 0.5f32).approx_eq(&q0.nlerp(&q1, 0.5f32)));
+
+    for i in 0..11 {
+        let amount = i as f32 / 10f32;
+        assert!(q0.nlerp(&q1, amount).magnitude().approx_eq(&1f32));
+    }
+}
+
+#[test]
+fn to_axis_angle() {
+    let axis: Vector3<f64> = Vector3::new(1f64, -2f64, 3f64).normalize();
+    for &angle in [0.1f64, 1.0, 3.0, 3.14, 3.1415, 4.5].iter() {
+        let q: Quaternion<f64> = Rotation3::from_axis_angle(&axis, rad(angle));
+        let (a, Rad { s: t }) = q.to_axis_angle();
+        assert_approx_eq!(a, axis);
+        assert_approx_eq!(t, angle);
+    }
+
+    // not normalized
+    let q: Quaternion<f64> = Rotation3::from_axis_angle(&axis, rad(1f64));
+    let (a, Rad { s: t }) = q.mul_s(3f64).to_axis_angle();
+    assert_approx_eq!(a, axis);
+    assert_approx_eq!(t, 1f64);
+
+    // tiny angles keep their axis
+    let z: Vector3<f64> = Vector3::unit_z();
+    for &angle in [1.0e-5f64, 1.0e-9].iter() {
+        let q: Quaternion<f64> = Rotation3::from_axis_angle(&z, rad(angle));
+        let (a, Rad { s: t }) = q.to_axis_angle();
+        assert_approx_eq!(a, z);
+        assert!(t.approx_eq_eps(&angle, &(angle * 1.0e-9)));
+    }
+
+    // the identity has no well defined axis
+    let (a, Rad { s: t }) = Quaternion::<f64>::identity().to_axis_angle();
+    assert_eq!(a, Vector3::unit_x());
+    assert_eq!(t, 0f64);
+}
+
+#[test]
+fn look_at() {
+    let up: Vector3<f64> = Vector3::unit_y();
+    let dirs = [Vector3::new(0f64, 0f64, -1f64), Vector3::new(0f64, 0f64, 1f64),
+                Vector3::new(1f64, 0f64, 0f64), Vector3::new(-1f64, 0.5f64, 0.2f64),
+                Vector3::new(0.3f64, -2f64, 1f64),
+                // nearly a half turn, where the quaternion's real part is
+                // close to zero
+                Vector3::new(1.0e-6f64, 2.0e-6f64, -1f64)];
+    for dir in dirs.iter() {
+        let q: Quaternion<f64> = Rotation::look_at(dir, &up);
+        assert_approx_eq!(q.magnitude(), 1f64);
+        assert_approx_eq!(q.rotate_vector(&dir.normalize()), Vector3::unit_z());
+        assert_approx_eq!(q.to_matrix3(), Matrix3::look_at(dir, &up));
+    }
+
+    // looking straight up or down falls back to another up vector
+    for dir in [Vector3::new(0f64, 1f64, 0f64), Vector3::new(0f64, -3f64, 0f64)].iter() {
+        let q: Quaternion<f64> = Rotation::look_at(dir, &up);
+        assert!(!q.s.is_nan() && !q.v.x.is_nan() && !q.v.y.is_nan() && !q.v.z.is_nan());
+        assert_approx_eq!(q.magnitude(), 1f64);
+        assert_approx_eq!(q.rotate_vector(&dir.normalize()), Vector3::unit_z());
+        assert_approx_eq!(q.to_matrix3(), Matrix3::look_at(dir, &up));
+    }
+}
+
+#[test]
+fn from_arc() {
+    let x = Vector3::new(2f64, 0f64, 0f64);
+
+    // parallel
+    assert_approx_eq!(Quaternion::from_arc(&x, &Vector3::new(5f64, 0f64, 0f64)), Quaternion::identity());
+
+    // perpendicular
+    let q = Quaternion::from_arc(&x, &Vector3::new(0f64, 3f64, 0f64));
+    let expected: Quaternion<f64> = Rotation3::from_axis_angle(&Vector3::unit_z(), rad(f64::consts::FRAC_PI_2));
+    assert_approx_eq!(q, expected);
+
+    let from = Vector3::new(1f64, -2f64, 0.5f64);
+    let to = Vector3::new(-3f64, 1f64, 2f64);
+    let q = Quaternion::from_arc(&from, &to);
+    assert_approx_eq!(q.rotate_vector(&from).normalize(), to.normalize());
+
+    // antiparallel
+    for v in [x, Vector3::new(1f64, 1f64, -1f64), Vector3::new(0f64, -1f64, 0f64)].iter() {
+        let q = Quaternion::from_arc(v, &-*v);
+        assert_approx_eq!(q.magnitude(), 1f64);
+        assert_approx_eq!(q.rotate_vector(v), -*v);
+    }
+
+    let q: Quaternion<f64> = Rotation::between_vectors(&Vector3::unit_z(), &-Vector3::unit_z());
+    assert_approx_eq!(q.rotate_vector(&Vector3::unit_z()), -Vector3::unit_z());
+
+    // nearly antiparallel, which must not be snapped to a half turn
+    let from = Vector3::new(1f64, 0f64, 0f64);
+    for &e in [4.0e-3f64, 1.0e-6, 1.0e-9].iter() {
+        let to = Vector3::new(-1f64, e, 0f64);
+        let q = Quaternion::from_arc(&from, &to);
+        assert_approx_eq!(q.magnitude(), 1f64);
+        assert_approx_eq_eps!(q.rotate_vector(&from).normalize(), to.normalize(), e * 1.0e-3);
+    }
+}
+
+#[test]
+fn rotate_vector_fast() {
+    let axis = Vector3::new(1.0f64, -2.0, 0.5).normalize();
+    let q: Quaternion<f64> = Rotation3::from_axis_angle(&axis, rad(1.2f64));
+    let m = q.to_matrix3();
+    let v = Vector3::new(0.3f64, 4.0, -1.5);
+
+    assert!(q.rotate_vector_fast(&v).approx_eq(&m.mul_v(&v)));
+    assert!(q.rotate_vector_fast(&v).approx_eq(&q.rotate_vector(&v)));
+
+    // Points are rotated about the origin.
+    let p = Point3::new(0.3f64, 4.0, -1.5);
+    let r = q.rotate_point(&p);
+    assert!(Vector3::new(r.x, r.y, r.z).approx_eq(&m.mul_v(&v)));
+}
+
+#[test]
+fn test_integrate() {
+    let start = Quaternion::from_axis_angle(&Vector3::new(0.0f64, 1.0, 0.0), rad(0.3));
+    let omega = Vector3::new(0.5f64, -1.0, 2.0);
+
+    let mut stepped = start;
+    for _ in 0..1000 {
+        stepped = stepped.integrate(&omega, 0.001);
+    }
+    let single = start.integrate(&omega, 1.0);
+    assert!(stepped.approx_eq_eps(&single, &1.0e-9));
+
+    let axis = omega.normalize();
+    let expected = Quaternion::from_axis_angle(&axis, rad(omega.length())).mul_q(&start);
+    assert!(single.approx_eq(&expected));
+
+    // A zero angular velocity leaves the orientation unchanged
+    assert_eq!(start.integrate(&Vector3::new(0.0f64, 0.0, 0.0), 0.1), start.normalize());
+    assert!(start.integrate(&Vector3::new(1.0e-12f64, 0.0, 0.0), 1.0).approx_eq(&start));
+}
+
+#[test]
+fn test_exp_ln() {
+    let rotations = [
+        Quaternion::from_axis_angle(&Vector3::new(1.0f64, 0.0, 0.0), rad(0.5)),
+        Quaternion::from_axis_angle(&Vector3::new(0.0f64, 0.6, 0.8), rad(2.0)),
+        Quaternion::from_axis_angle(&Vector3::new(0.0f64, 0.0, 1.0), rad(3.0)),
+        Quaternion::new(2.0f64, -1.0, 0.5, 3.0),
+    ];
+    for q in rotations.iter() {
+        assert!(q.ln().exp().approx_eq(q));
+        assert!(q.powf(1.0).approx_eq(q));
+        assert!(q.powf(2.0).approx_eq(&q.mul_q(q)));
+    }
+
+    // The logarithm of a unit quaternion is a pure quaternion
+    let q = rotations[1];
+    assert!(q.ln().s.approx_eq(&0.0));
+    assert!(q.ln().v.approx_eq(&Vector3::new(0.0, 0.6, 0.8)));
+
+    let identity: Quaternion<f64> = Quaternion::identity();
+    assert_eq!(identity.ln(), Quaternion::zero());
+    assert_eq!(Quaternion::<f64>::zero().exp(), identity);
+
+    let half = Quaternion::from_axis_angle(&Vector3::new(0.0f64, 1.0, 0.0), rad(1.0));
+    assert!(half.powf(0.5).approx_eq(&Quaternion::from_axis_angle(&Vector3::new(0.0f64, 1.0, 0.0), rad(0.5))));
+}
+
+#[test]
+fn test_squad() {
+    let axis = Vector3::new(0.0f64, 0.0, 1.0);
+    let keys = [
+        Quaternion::identity(),
+        Quaternion::from_axis_angle(&axis, rad(0.4)),
+        Quaternion::from_axis_angle(&Vector3::new(1.0f64, 0.0, 0.0), rad(0.5)).mul_q(&Quaternion::from_axis_angle(&axis, rad(0.4))),
+        Quaternion::from_axis_angle(&Vector3::new(0.0f64, 1.0, 0.0), rad(0.6)),
+        Quaternion::from_axis_angle(&axis, rad(-0.3)),
+    ];
+
+    // Matches the keys at the segment endpoints
+    assert!(Quaternion::squad(&keys[0], &keys[1], &keys[2], &keys[3], 0.0).approx_eq(&keys[1]));
+    assert!(Quaternion::squad(&keys[0], &keys[1], &keys[2], &keys[3], 1.0).approx_eq(&keys[2]));
+
+    // Compare the change in velocity across the join at `keys[2]`
+    let h = 1.0e-4;
+    let squad_a = |t| Quaternion::squad(&keys[0], &keys[1], &keys[2], &keys[3], t);
+    let squad_b = |t| Quaternion::squad(&keys[1], &keys[2], &keys[3], &keys[4], t);
+    let squad_jump = squad_b(h).sub_q(&keys[2]).add_q(&squad_a(1.0 - h).sub_q(&keys[2])).magnitude();
+
+    let slerp_jump = keys[2].slerp(&keys[3], h).sub_q(&keys[2])
+                            .add_q(&keys[1].slerp(&keys[2], 1.0 - h).sub_q(&keys[2])).magnitude();
+
+    assert!(squad_jump < slerp_jump * 1.0e-2);
+}
+
+#[test]
+fn test_is_finite_is_nan() {
+    let q = Quaternion::new(1.0f64, 0.0, 0.0, 0.0);
+    assert!(q.is_finite());
+    assert!(!q.is_nan());
+    assert!(Quaternion::new(f64::NAN, 0.0, 0.0, 0.0).is_nan());
+    assert!(!Quaternion::new(1.0f64, 0.0, f64::INFINITY, 0.0).is_finite());
+}
+
+#[test]
+fn test_in_place_ops() {
+    let a = Quaternion::new(1.0f64, -2.0, 0.5, 3.0);
+    let b = Quaternion::new(-0.5f64, 1.0, 2.0, -1.0);
+
+    let mut q = a; q.add_self_q(&b); assert_eq!(q, a.add_q(&b));
+    let mut q = a; q.sub_self_q(&b); assert_eq!(q, a.sub_q(&b));
+    let mut q = a; q.mul_self_q(&b); assert_eq!(q, a.mul_q(&b));
+    let mut q = a; q.mul_self_s(2.0); assert_eq!(q, a.mul_s(2.0));
+    let mut q = a; q.div_self_s(2.0); assert_eq!(q, a.div_s(2.0));
+    let mut q = a; q.neg_self(); assert_eq!(q, -a);
+
+    let mut q = a; q.concat_self(&b); assert_eq!(q, a.concat(&b));
+}
+
+#[test]
+fn test_operators() {
+    let a = Quaternion::new(1.0f64, -2.0, 0.5, 3.0);
+    let b = Quaternion::new(-0.5f64, 1.0, 2.0, -1.0);
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+
+    assert_eq!(a + b, a.add_q(&b));
+    assert_eq!(a - b, a.sub_q(&b));
+    assert_eq!(a * b, a.mul_q(&b));
+    assert_eq!(a * 2.0, a.mul_s(2.0));
+    assert_eq!(a * v, a.mul_v(&v));
+}
+
+#[test]
+fn test_default() {
+    use std::default::Default;
+
+    let q: Quaternion<f64> = Default::default();
+    assert_eq!(q, Quaternion::identity());
+}
+
+#[test]
+fn test_matrix3_to_quaternion() {
+    let axes = [Vector3::new(1.0f64, 0.0, 0.0), Vector3::new(0.0f64, 1.0, 0.0), Vector3::new(0.0f64, 0.0, 1.0),
+                Vector3::new(1.0f64, 2.0, -3.0).normalize(), Vector3::new(-1.0f64, 1.0, 1.0).normalize()];
+    let angles = [0.0f64, 0.5, 2.0, 3.0, f64::consts::PI, -2.9];
+
+    let mut negative_trace = 0;
+    for axis in axes.iter() {
+        for &angle in angles.iter() {
+            let q = Quaternion::from_axis_angle(axis, rad(angle));
+            let m = q.to_matrix3();
+            if m.trace() < -0.5 { negative_trace += 1; }
+
+            let converted = m.to_quaternion();
+            assert!(converted.approx_eq(&q) || converted.approx_eq(&-q));
+            assert!(converted.magnitude().approx_eq(&1.0));
+            assert!(converted.to_matrix3().approx_eq(&m));
+        }
+    }
+    assert!(negative_trace > 0);
+}
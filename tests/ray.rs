@@ -0,0 +1,32 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Ray, Ray3, Point, Point3, Vector3, ApproxEq};
+
+#[test]
+fn test_point_at() {
+    let ray: Ray3<f64> = Ray::new(Point3::new(1.0, 2.0, 3.0), Vector3::new(0.0, -1.0, 0.5));
+    assert_eq!(ray.point_at(0.0), ray.origin);
+    assert_eq!(ray.point_at(2.0), Point3::new(1.0, 0.0, 4.0));
+}
+
+#[test]
+fn test_new_normalized() {
+    let ray: Ray3<f64> = Ray::new_normalized(Point3::origin(), Vector3::new(0.0, 3.0, 4.0));
+    assert!(ray.direction.approx_eq(&Vector3::new(0.0, 0.6, 0.8)));
+    assert!(ray.point_at(5.0).approx_eq(&Point3::new(0.0, 3.0, 4.0)));
+}
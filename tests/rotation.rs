@@ -41,3 +41,56 @@ fn test_invert_basis3() {
     let a: Basis3<_> = rotation::a3();
     assert!(a.concat(&a.invert()).as_matrix3().is_identity());
 }
+
+#[test]
+fn test_basis2_to_angle() {
+    use std::f64::consts::PI;
+
+    for i in -7..9 {
+        let angle = rad(i as f64 * PI / 8.0);
+        let b: Basis2<f64> = Rotation2::from_angle(angle);
+        assert!(b.to_angle().approx_eq(&angle));
+    }
+
+    // angles outside of the range are wrapped into it
+    let b: Basis2<f64> = Rotation2::from_angle(rad(-PI));
+    assert!(b.to_angle().approx_eq(&rad(PI)));
+    let b: Basis2<f64> = Rotation2::from_angle(rad(1.5 * PI));
+    assert!(b.to_angle().approx_eq(&rad(-0.5 * PI)));
+    let b: Basis2<f64> = Rotation2::from_angle(rad(2.0 * PI + 0.1));
+    assert!(b.to_angle().approx_eq(&rad(0.1)));
+}
+
+#[test]
+fn test_basis3_look_at() {
+    let dir = Vector3::new(1.0f64, 2.0, -3.0);
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let b: Basis3<f64> = Rotation::look_at(&dir, &up);
+    assert!(b.rotate_vector(&dir.normalize()).approx_eq(&Vector3::unit_z()));
+    assert!(b.as_matrix3().is_invertible());
+    assert!(b.as_matrix3().determinant().approx_eq(&1.0));
+
+    // `up` parallel to `dir` falls back to another up vector.
+    for dir in [Vector3::new(0.0f64, 2.0, 0.0),
+                Vector3::new(0.0f64, -1.0, 0.0),
+                Vector3::new(1.0f64, 0.0, 0.0)].iter() {
+        let b: Basis3<f64> = Rotation::look_at(dir, dir);
+        assert!(b.rotate_vector(&dir.normalize()).approx_eq(&Vector3::unit_z()));
+        assert!(b.as_matrix3().determinant().approx_eq(&1.0));
+        let m = b.as_matrix3().mul_m(&b.as_matrix3().transpose());
+        assert!(m.is_identity());
+    }
+}
+
+#[test]
+fn test_rotate_around() {
+    let pivot = Point2::new(1.0f64, 1.0);
+    let rot: Basis2<f64> = Rotation2::from_angle(deg(90.0f64).to_rad());
+    assert!(rot.rotate_around(&Point2::new(3.0f64, 1.0), &pivot).approx_eq(&Point2::new(1.0, 3.0)));
+    assert!(rot.rotate_around(&pivot, &pivot).approx_eq(&pivot));
+
+    let pivot = Point3::new(0.0f64, 2.0, 5.0);
+    let rot: Quaternion<f64> = Rotation3::from_angle_z(deg(90.0f64).to_rad());
+    assert!(rot.rotate_around(&Point3::new(1.0f64, 2.0, 5.0), &pivot).approx_eq(&Point3::new(0.0, 3.0, 5.0)));
+    assert!(rot.rotate_around(&Point3::new(0.0f64, 0.0, 0.0), &pivot).approx_eq(&Point3::new(2.0, 2.0, 0.0)));
+}
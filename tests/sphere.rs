@@ -32,6 +32,62 @@ fn test_intersection() {
     assert_eq!((sphere,r3).intersection(), None);
 }
 
+#[test]
+fn test_intersects_ray() {
+    let sphere = Sphere::new(Point3::new(0f64, 0f64, 0f64), 1f64);
+
+    // the nearer of the two hits
+    let r = Ray::new(Point3::new(0f64, 0f64, 5f64), Vector3::new(0f64, 0f64, -2f64));
+    assert_eq!(sphere.intersects_ray(&r), Some(2f64));
+
+    // starting inside leaves through the far side
+    let r = Ray::new(Point3::new(0f64, 0f64, 0.5f64), Vector3::new(0f64, 0f64, -1f64));
+    assert_eq!(sphere.intersects_ray(&r), Some(1.5f64));
+    assert_eq!((sphere, r).intersection(), Some(Point3::new(0f64, 0f64, -1f64)));
+
+    // tangent to the sphere
+    let r = Ray::new(Point3::new(1f64, 0f64, 5f64), Vector3::new(0f64, 0f64, -1f64));
+    assert_eq!(sphere.intersects_ray(&r), Some(5f64));
+
+    // behind the ray
+    let r = Ray::new(Point3::new(0f64, 0f64, 5f64), Vector3::new(0f64, 0f64, 1f64));
+    assert_eq!(sphere.intersects_ray(&r), None);
+}
+
+#[test]
+fn test_contains() {
+    let sphere = Sphere::new(Point3::new(1f64, 1f64, 1f64), 2f64);
+    assert!(sphere.contains(&Point3::new(1f64, 1f64, 1f64)));
+    assert!(sphere.contains(&Point3::new(1f64, -1f64, 1f64)));
+    assert!(!sphere.contains(&Point3::new(2.5f64, 2.5f64, 1f64)));
+}
+
+#[test]
+fn test_intersects() {
+    let a = Sphere::new(Point3::new(0f64, 0f64, 0f64), 1f64);
+    assert!(a.intersects(&a));
+    assert!(a.intersects(&Sphere::new(Point3::new(1.5f64, 0f64, 0f64), 1f64)));
+    assert!(a.intersects(&Sphere::new(Point3::new(0f64, 3f64, 0f64), 2f64)));
+    assert!(!a.intersects(&Sphere::new(Point3::new(0f64, 0f64, 3f64), 1.5f64)));
+}
+
+#[test]
+fn test_from_points() {
+    assert_eq!(Sphere::<f64>::from_points(&[]), None);
+
+    let p = Point3::new(1f64, 2f64, 3f64);
+    assert_eq!(Sphere::from_points(&[p]), Some(Sphere::new(p, 0f64)));
+
+    let points = [Point3::new(-1f64, 0f64, 0f64), Point3::new(1f64, 0f64, 0f64),
+                  Point3::new(0f64, 0.5f64, 0f64), Point3::new(0f64, 0f64, 1.5f64),
+                  Point3::new(0.2f64, -0.9f64, 0.1f64)];
+    let sphere = Sphere::from_points(&points).unwrap();
+    for p in points.iter() {
+        assert!(sphere.radius + 1e-10 >= p.sub_p(&sphere.center).length());
+    }
+    assert!(sphere.radius < 2f64);
+}
+
 #[test]
 fn test_bound() {
     let point = Point3::new(1f32, 2.0, 3.0);
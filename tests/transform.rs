@@ -15,6 +15,9 @@
 
 
 extern crate cgmath;
+extern crate rustc_serialize;
+#[cfg(feature = "serde_serialization")]
+extern crate serde;
 
 use cgmath::*;
 
@@ -38,10 +41,28 @@ fn test_look_at() {
 	let up = Vector3::new(1.0f64, 0.0, 0.0);
 	let t: Decomposed<f64,Vector3<f64>,Quaternion<f64>> = Transform::look_at(&eye, &center, &up);
 	let point = Point3::new(1.0f64, 0.0, 0.0);
-	let view_point = Point3::new(0.0f64, 1.0, 5.0);
+	let view_point = Point3::new(0.0f64, 1.0, -5.0);
 	assert!( t.transform_point(&point).approx_eq(&view_point) );
 }
 
+#[test]
+fn test_look_at_matches_matrix() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(-2.0f64, 0.5, -1.0);
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let t: Decomposed<f64,Vector3<f64>,Quaternion<f64>> = Transform::look_at(&eye, &center, &up);
+    let m: AffineMatrix3<f64> = Transform::look_at(&eye, &center, &up);
+
+    assert!(t.transform_point(&eye).approx_eq(&Point3::origin()));
+    assert!(t.transform_point(&eye).approx_eq(&m.transform_point(&eye)));
+    assert!(t.transform_point(&center).approx_eq(&m.transform_point(&center)));
+    assert!(t.transform_point(&Point3::new(4.0f64, -1.0, 0.5)).approx_eq(&m.transform_point(&Point3::new(4.0f64, -1.0, 0.5))));
+    assert!(t.to_matrix4().approx_eq(&Matrix4::look_at(&eye, &center, &up)));
+
+    let b: Decomposed<f64,Vector3<f64>,Basis3<f64>> = Transform::look_at(&eye, &center, &up);
+    assert!(b.to_matrix4().approx_eq(&Matrix4::look_at(&eye, &center, &up)));
+}
+
 #[test]
 fn test_components() {
 	let t = Decomposed {
@@ -54,3 +75,120 @@ fn test_components() {
     assert_eq!(rot, t.rot);
     assert_eq!(disp, t.disp);
 }
+
+#[test]
+fn test_non_uniform() {
+    let rot: Quaternion<f64> = Rotation3::from_axis_angle(&Vector3::new(1.0f64, 2.0, -1.0).normalize(), rad(0.7f64));
+    let t = NonUniformDecomposed {
+        scale: Vector3::new(2.0f64, 0.5, 3.0),
+        rot: rot,
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    let p = Point3::new(1.0f64, -2.0, 0.5);
+    let v = Vector3::new(-1.0f64, 4.0, 2.0);
+    let m = t.to_matrix4();
+    assert!(t.transform_point(&p).to_homogeneous().approx_eq(&m.mul_v(&p.to_homogeneous())));
+    assert!(t.transform_vector(&v).extend(0.0).approx_eq(&m.mul_v(&v.extend(0.0))));
+
+    // a rotation between two non-uniform scales cannot be decomposed
+    assert!(t.concat(&t).is_none());
+    assert!(t.invert().is_none());
+
+    // but it can when the outer scale is uniform
+    let u = NonUniformDecomposed { scale: Vector3::from_value(1.5f64), rot: rot, disp: Vector3::new(1.0f64, 0.0, -1.0) };
+    let ut = u.concat(&t).unwrap();
+    assert!(ut.transform_point(&p).approx_eq(&u.transform_point(&t.transform_point(&p))));
+    assert!(ut.to_matrix4().approx_eq(&u.to_matrix4().mul_m(&m)));
+    let ui = u.invert().unwrap();
+    assert!(ui.transform_point(&u.transform_point(&p)).approx_eq(&p));
+
+    // or when the inner transform does not rotate
+    let s = NonUniformDecomposed { scale: Vector3::new(1.0f64, 2.0, 4.0), rot: Quaternion::identity(), disp: Vector3::new(0.0f64, 1.0, 0.0) };
+    let ts = t.concat(&s).unwrap();
+    assert!(ts.transform_point(&p).approx_eq(&t.transform_point(&s.transform_point(&p))));
+    let si = s.invert().unwrap();
+    assert!(si.transform_point(&s.transform_point(&p)).approx_eq(&p));
+
+    let zero = NonUniformDecomposed { scale: Vector3::new(1.0f64, 0.0, 1.0), rot: Quaternion::identity(), disp: Vector3::zero() };
+    assert!(zero.invert().is_none());
+
+    // a scale that is only nearly uniform still cannot pass through a rotation
+    let near = NonUniformDecomposed { scale: Vector3::new(1.0f64, 1.000005, 1.0), rot: rot, disp: Vector3::zero() };
+    assert!(!near.is_uniform());
+    assert!(near.concat(&t).is_none());
+    assert!(near.invert().is_none());
+
+    // a tiny scale is still invertible
+    let tiny = NonUniformDecomposed { scale: Vector3::new(1e-6f64, 1.0, 2.0), rot: Quaternion::identity(), disp: Vector3::new(1.0f64, 0.0, 0.0) };
+    let tinyi = tiny.invert().unwrap();
+    assert!(tinyi.scale.approx_eq(&Vector3::new(1e6f64, 1.0, 0.5)));
+    assert!(tinyi.transform_point(&tiny.transform_point(&p)).approx_eq(&p));
+}
+
+#[cfg(feature = "serde_serialization")]
+#[test]
+fn test_serde_json_round_trip() {
+    use serde::json;
+
+    let t = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::new(0.5f64, 0.5, 0.5, 0.5),
+        disp: Vector3::new(1.5f64, -2.0, 0.25),
+    };
+    let encoded = json::to_string(&t).unwrap();
+    let decoded: Decomposed<f64, Vector3<f64>, Quaternion<f64>> = json::from_str(&encoded).unwrap();
+
+    assert!(decoded.scale.approx_eq(&t.scale));
+    assert!(decoded.rot.approx_eq(&t.rot));
+    assert!(decoded.disp.approx_eq(&t.disp));
+
+    let p = Point2::new(1.0f32, -3.5);
+    assert_eq!(json::from_str::<Point2<f32>>(&json::to_string(&p).unwrap()).unwrap(), p);
+    let m = Matrix4::from_translation(&Vector3::new(1.0f64, 2.0, 3.0));
+    assert_eq!(json::from_str::<Matrix4<f64>>(&json::to_string(&m).unwrap()).unwrap(), m);
+    let a = (rad(0.5f64), deg(90.0f64));
+    assert_eq!(json::from_str::<Rad<f64>>(&json::to_string(&a.0).unwrap()).unwrap(), a.0);
+    assert_eq!(json::from_str::<Deg<f64>>(&json::to_string(&a.1).unwrap()).unwrap(), a.1);
+}
+
+#[test]
+fn test_transform_bound() {
+    let aabb = Aabb3::new(Point3::new(-1.0f64, -2.0, -3.0), Point3::new(2.0, 1.0, 0.5));
+    let rot: Basis3<f64> = Rotation3::from_euler(rad(0.3f64), rad(-1.1f64), rad(0.7f64));
+    let t = Decomposed {
+        scale: 2.0f64,
+        rot: rot,
+        disp: Vector3::new(5.0f64, -4.0, 1.0),
+    };
+
+    // Bounding the eight transformed corners gives the tightest possible box
+    let corners = aabb.to_corners();
+    let first = t.transform_point(&corners[0]);
+    let expected = corners[1..].iter().fold(Aabb3::new(first, first),
+        |acc, p| acc.grow(&t.transform_point(p)));
+
+    let bound = t.transform_bound(&aabb);
+    assert!(bound.min.approx_eq(&expected.min));
+    assert!(bound.max.approx_eq(&expected.max));
+}
+
+#[test]
+fn test_concat() {
+    let a = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::from_angle_z(rad(0.5f64)),
+        disp: Vector3::new(1.0f64, -2.0, 3.0),
+    };
+    let b = Decomposed {
+        scale: 0.5f64,
+        rot: Quaternion::from_angle_x(rad(-1.2f64)),
+        disp: Vector3::new(-4.0f64, 5.0, 0.25),
+    };
+    let expected = a.to_matrix4().mul_m(&b.to_matrix4());
+
+    assert!(a.concat(&b).to_matrix4().approx_eq(&expected));
+
+    let mut c = a;
+    c.concat_self(&b);
+    assert!(c.to_matrix4().approx_eq(&expected));
+}
@@ -0,0 +1,68 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Point2, Point3};
+use cgmath::{triangle_area, barycentric};
+use cgmath::ApproxEq;
+use std::num::Float;
+
+#[test]
+fn test_triangle_area() {
+    let a = Point2::new(0.0f64, 0.0);
+    let b = Point2::new(4.0f64, 0.0);
+    let c = Point2::new(0.0f64, 3.0);
+    assert!(triangle_area(&a, &b, &c).approx_eq(&6.0));
+    assert!(triangle_area(&a, &c, &b).approx_eq(&6.0));
+
+    let a = Point3::new(1.0f64, 0.0, 0.0);
+    let b = Point3::new(0.0f64, 1.0, 0.0);
+    let c = Point3::new(0.0f64, 0.0, 1.0);
+    assert!(triangle_area(&a, &b, &c).approx_eq(&(3.0f64.sqrt() / 2.0)));
+
+    // Collinear points
+    let d = Point3::new(2.0f64, -1.0, 0.0);
+    assert!(triangle_area(&a, &b, &d).approx_eq(&0.0));
+}
+
+#[test]
+fn test_barycentric() {
+    let a = Point3::new(1.0f64, 0.0, 2.0);
+    let b = Point3::new(4.0f64, 1.0, 0.0);
+    let c = Point3::new(0.0f64, 3.0, 1.0);
+
+    let (u, v, w) = barycentric(&a, &a, &b, &c).unwrap();
+    assert!(u.approx_eq(&1.0) && v.approx_eq(&0.0) && w.approx_eq(&0.0));
+    let (u, v, w) = barycentric(&b, &a, &b, &c).unwrap();
+    assert!(u.approx_eq(&0.0) && v.approx_eq(&1.0) && w.approx_eq(&0.0));
+    let (u, v, w) = barycentric(&c, &a, &b, &c).unwrap();
+    assert!(u.approx_eq(&0.0) && v.approx_eq(&0.0) && w.approx_eq(&1.0));
+
+    let centroid = Point3::new(5.0f64 / 3.0, 4.0 / 3.0, 1.0);
+    let (u, v, w) = barycentric(&centroid, &a, &b, &c).unwrap();
+    let third = 1.0f64 / 3.0;
+    assert!(u.approx_eq(&third) && v.approx_eq(&third) && w.approx_eq(&third));
+
+    // Points outside the triangle have a negative coordinate.
+    let (u, v, w) = barycentric(&Point2::new(-1.0f64, 0.5), &Point2::new(0.0, 0.0),
+                                &Point2::new(1.0, 0.0), &Point2::new(0.0, 1.0)).unwrap();
+    assert!(u.approx_eq(&1.5) && v.approx_eq(&-1.0) && w.approx_eq(&0.5));
+
+    // Degenerate triangles
+    assert!(barycentric(&a, &a, &a, &c).is_none());
+    assert!(barycentric(&Point2::new(0.0f64, 0.0), &Point2::new(0.0, 0.0),
+                        &Point2::new(1.0, 1.0), &Point2::new(2.0, 2.0)).is_none());
+}
@@ -87,6 +87,50 @@ fn test_comp_max() {
     assert_eq!(Vector4::new(5.0f64, 6.0f64, 7.0f64, 8.0f64).comp_max(), 8.0f64);
 }
 
+#[test]
+fn test_comp_mixed_sign() {
+    let v = Vector4::new(-2.0f64, 3.0f64, -0.5f64, 4.0f64);
+    assert_eq!(v.comp_add(), 4.5f64);
+    assert_eq!(v.comp_mul(), 12.0f64);
+    assert_eq!(v.comp_min(), -2.0f64);
+    assert_eq!(v.comp_max(), 4.0f64);
+
+    let v = Vector3::new(-3isize, 5isize, -1isize);
+    assert_eq!(v.comp_add(), 1isize);
+    assert_eq!(v.comp_mul(), 15isize);
+    assert_eq!(v.comp_min(), -3isize);
+    assert_eq!(v.comp_max(), 5isize);
+}
+
+#[test]
+fn test_min_max() {
+    let a = Vector3::new(-1isize, 5isize, 0isize);
+    let b = Vector3::new(2isize, -3isize, 0isize);
+    assert_eq!(a.min(&b), Vector3::new(-1isize, -3isize, 0isize));
+    assert_eq!(a.max(&b), Vector3::new(2isize, 5isize, 0isize));
+
+    let a = Vector4::new(-1.5f64, 2.0f64, -0.5f64, 8.0f64);
+    let b = Vector4::new(1.0f64, -2.0f64, -0.25f64, 3.0f64);
+    assert_eq!(a.min(&b), Vector4::new(-1.5f64, -2.0f64, -0.5f64, 3.0f64));
+    assert_eq!(a.max(&b), Vector4::new(1.0f64, 2.0f64, -0.25f64, 8.0f64));
+
+    // NaN components are ignored
+    let a = Vector2::new(f64::NAN, 1.0f64);
+    let b = Vector2::new(-2.0f64, f64::NAN);
+    assert_eq!(a.min(&b), Vector2::new(-2.0f64, 1.0f64));
+    assert_eq!(a.max(&b), Vector2::new(-2.0f64, 1.0f64));
+}
+
+#[test]
+fn test_clamp() {
+    let lo = Vector3::new(-1.0f64, -1.0f64, -1.0f64);
+    let hi = Vector3::new(1.0f64, 2.0f64, 3.0f64);
+    assert_eq!(Vector3::new(-5.0f64, 0.5f64, 4.0f64).clamp(&lo, &hi), Vector3::new(-1.0f64, 0.5f64, 3.0f64));
+    assert_eq!(Vector3::new(0.0f64, 0.0f64, 0.0f64).clamp(&lo, &hi), Vector3::new(0.0f64, 0.0f64, 0.0f64));
+    assert_eq!(Vector2::new(-7isize, 7isize).clamp(&Vector2::new(-2isize, -2isize), &Vector2::new(2isize, 2isize)),
+               Vector2::new(-2isize, 2isize));
+}
+
 #[test]
 fn test_cross() {
     let a = Vector3::new(1isize, 2isize, 3isize);
@@ -164,21 +208,478 @@ fn test_angle() {
 
 #[test]
 fn test_normalize() {
-    // TODO: test normalize_to, normalize_sel.0, and normalize_self_to
     assert!(Vector2::new(3.0f64, 4.0f64).normalize().approx_eq( &Vector2::new(3.0/5.0, 4.0/5.0) ));
     assert!(Vector3::new(2.0f64, 3.0f64, 6.0f64).normalize().approx_eq( &Vector3::new(2.0/7.0, 3.0/7.0, 6.0/7.0) ));
     assert!(Vector4::new(1.0f64, 2.0f64, 4.0f64, 10.0f64).normalize().approx_eq( &Vector4::new(1.0/11.0, 2.0/11.0, 4.0/11.0, 10.0/11.0) ));
+
+    assert!(Vector2::new(3.0f64, 4.0f64).normalize_to(10.0).approx_eq( &Vector2::new(6.0, 8.0) ));
+    let mut v = Vector2::new(3.0f64, 4.0f64); v.normalize_self_to(10.0);
+    assert!(v.approx_eq( &Vector2::new(6.0, 8.0) ));
+    let mut v = Vector3::new(2.0f64, 3.0f64, 6.0f64); v.normalize_self();
+    assert!(v.approx_eq( &Vector3::new(2.0/7.0, 3.0/7.0, 6.0/7.0) ));
+
+    // the zero vector has no direction, so it is left unchanged
+    assert_eq!(Vector3::<f64>::zero().normalize(), Vector3::zero());
+    assert_eq!(Vector3::<f64>::zero().normalize_to(10.0), Vector3::zero());
+    let mut v = Vector3::<f64>::zero(); v.normalize_self();
+    assert_eq!(v, Vector3::zero());
+}
+
+#[test]
+fn test_limit_length() {
+    // longer vectors are clamped, keeping their direction
+    assert!(Vector2::new(6.0f64, 8.0f64).limit_length(5.0).approx_eq( &Vector2::new(3.0, 4.0) ));
+    assert!(Vector3::new(0.0f64, -4.0f64, 0.0f64).limit_length(1.0).approx_eq( &Vector3::new(0.0, -1.0, 0.0) ));
+
+    // shorter vectors are returned unchanged
+    assert_eq!(Vector2::new(0.3f64, 0.4f64).limit_length(5.0), Vector2::new(0.3, 0.4));
+    assert_eq!(Vector3::<f64>::zero().limit_length(1.0), Vector3::zero());
+}
+
+#[test]
+fn test_project_on() {
+    let v = Vector3::new(3.0f64, -4.0f64, 5.0f64);
+    assert_eq!(v.project_on(&Vector3::unit_x()), Vector3::new(3.0f64, 0.0f64, 0.0f64));
+    assert_eq!(v.project_on(&Vector3::new(0.0f64, 2.0f64, 0.0f64)), Vector3::new(0.0f64, -4.0f64, 0.0f64));
+    assert_eq!(v.reject_from(&Vector3::unit_z()), Vector3::new(3.0f64, -4.0f64, 0.0f64));
+
+    let diag = Vector2::new(1.0f64, 1.0f64);
+    let v = Vector2::new(2.0f64, 0.0f64);
+    assert!(v.project_on(&diag).approx_eq(&Vector2::new(1.0f64, 1.0f64)));
+    assert!(v.reject_from(&diag).approx_eq(&Vector2::new(1.0f64, -1.0f64)));
+    assert!(v.reject_from(&diag).is_perpendicular(&diag));
+
+    // projecting onto the zero vector
+    assert_eq!(v.project_on(&Vector2::zero()), Vector2::zero());
+    assert_eq!(v.reject_from(&Vector2::zero()), v);
+}
+
+#[test]
+fn test_reflect() {
+    let n = Vector2::new(0.0f64, 1.0f64);
+    assert_eq!(Vector2::new(1.0f64, -1.0f64).reflect(&n), Vector2::new(1.0f64, 1.0f64));
+    assert_eq!(Vector2::new(1.0f64, 0.0f64).reflect(&n), Vector2::new(1.0f64, 0.0f64));
+
+    let n = Vector3::new(1.0f64, 1.0f64, 0.0f64).normalize();
+    assert!(Vector3::new(-1.0f64, 0.0f64, 2.0f64).reflect(&n).approx_eq(&Vector3::new(0.0f64, 1.0f64, 2.0f64)));
+}
+
+#[test]
+fn test_refract() {
+    let n = Vector2::new(0.0f64, 1.0f64);
+    let i = Vector2::new(1.0f64, -1.0f64).normalize();
+
+    // passing straight through
+    assert!(i.refract(&n, 1.0f64).unwrap().approx_eq(&i));
+    assert!(Vector2::new(0.0f64, -1.0f64).refract(&n, 1.5f64).unwrap().approx_eq(&Vector2::new(0.0f64, -1.0f64)));
+
+    // from air into glass, sin(t) = sin(45°) / 1.5
+    let t = i.refract(&n, 1.0f64 / 1.5f64).unwrap();
+    assert!(t.x.approx_eq(&(f64::consts::FRAC_1_SQRT2 / 1.5f64)));
+    assert!(t.length().approx_eq(&1.0f64));
+    assert!(t.y < 0.0f64);
+
+    // total internal reflection from inside the glass
+    assert_eq!(i.refract(&n, 1.5f64), None);
+
+    // at grazing incidence the discriminant is exactly zero when the indices
+    // match, and the ray continues along the surface
+    let g = Vector2::new(1.0f64, 0.0f64);
+    assert_eq!(g.refract(&n, 1.0f64), Some(g));
+    assert_eq!(g.refract(&n, 1.01f64), None);
+    assert!(g.refract(&n, 0.5f64).unwrap().approx_eq(&Vector2::new(0.5f64, -f64::sqrt(0.75))));
+}
+
+#[test]
+fn test_lerp() {
+    let a = Vector3::new(1.0f64, -2.0f64, 4.0f64);
+    let b = Vector3::new(3.0f64, 2.0f64, 4.0f64);
+    assert_eq!(a.lerp(&b, 0.0f64), a);
+    assert_eq!(a.lerp(&b, 0.5f64), Vector3::new(2.0f64, 0.0f64, 4.0f64));
+    assert_eq!(a.lerp(&b, 1.0f64), b);
+
+    // extrapolation
+    assert_eq!(a.lerp(&b, -1.0f64), Vector3::new(-1.0f64, -6.0f64, 4.0f64));
+    assert_eq!(a.lerp(&b, 2.0f64), Vector3::new(5.0f64, 6.0f64, 4.0f64));
+
+    let mut c = a;
+    c.lerp_self(&b, 0.25f64);
+    assert_eq!(c, a.lerp(&b, 0.25f64));
 }
 
 #[test]
 fn test_map() {
     assert_eq!(Vector3::new(7.12f64, 3.8f64, -6.98f64).map(|x| x.floor()), Vector3::new(7.0f64, 3.0f64, -7.0f64));
     assert_eq!(Vector3::new(7.12f64, 3.8f64, -6.98f64).map(|x| x.max(0.0f64)), Vector3::new(7.12f64, 3.8f64, 0.0f64));
+
+    // `Array1::map` works in-place
+    let mut v = Vector2::new(1.5f64, -2.0f64);
+    v.map(|x| x * 2.0f64);
+    assert_eq!(v, Vector2::new(3.0f64, -4.0f64));
 }
 
 #[test]
 fn test_cast() {
-    assert_approx_eq!(Vector2::new(0.9f64, 1.5).cast(), Vector2::new(0.9f32, 1.5));
-    assert_approx_eq!(Vector3::new(1.0f64, 2.4, -3.13).cast(), Vector3::new(1.0f32, 2.4, -3.13));
-    assert_approx_eq!(Vector4::new(13.5f64, -4.6, -8.3, 2.41).cast(), Vector4::new(13.5f32, -4.6, -8.3, 2.41));
+    assert_approx_eq!(Vector2::new(0.9f64, 1.5).cast().unwrap(), Vector2::new(0.9f32, 1.5));
+    assert_approx_eq!(Vector3::new(1.0f64, 2.4, -3.13).cast().unwrap(), Vector3::new(1.0f32, 2.4, -3.13));
+    assert_approx_eq!(Vector4::new(13.5f64, -4.6, -8.3, 2.41).cast().unwrap(), Vector4::new(13.5f32, -4.6, -8.3, 2.41));
+
+    assert_eq!(Vector3::new(1i32, -2, 300).cast::<i16>(), Some(Vector3::new(1i16, -2, 300)));
+    assert_eq!(Vector3::new(1i32, -2, 300).cast::<u8>(), None);
+    assert_eq!(Vector2::new(-1i64, 2).cast::<usize>(), None);
+}
+
+#[test]
+fn test_norms() {
+    let v = Vector2::new(-3i32, 4i32);
+    assert_eq!(v.l1_norm(), 7i32);
+    assert_eq!(v.linf_norm(), 4i32);
+
+    let v = Vector3::new(2.5f64, -6.0f64, 1.0f64);
+    assert_eq!(v.l1_norm(), 9.5f64);
+    assert_eq!(v.linf_norm(), 6.0f64);
+
+    let v = Vector3::new(-7isize, 0isize, 5isize);
+    assert_eq!(v.l1_norm(), 12isize);
+    assert_eq!(v.linf_norm(), 7isize);
+}
+
+#[test]
+fn test_fixed_array_round_trip() {
+    let v: Vector2<f32> = FixedArray::from_fixed([1.0f32, 2.0]);
+    assert_eq!(v, Vector2::new(1.0f32, 2.0));
+    assert_eq!(v.into_fixed(), [1.0f32, 2.0]);
+
+    let v: Vector3<f32> = FixedArray::from_fixed([1.0f32, 2.0, 3.0]);
+    assert_eq!(v, Vector3::new(1.0f32, 2.0, 3.0));
+    assert_eq!(v.into_fixed(), [1.0f32, 2.0, 3.0]);
+
+    let v: Vector4<i32> = FixedArray::from_fixed([1i32, 2, 3, 4]);
+    assert_eq!(v, Vector4::new(1i32, 2, 3, 4));
+    assert_eq!(v.into_fixed(), [1i32, 2, 3, 4]);
+}
+
+#[test]
+fn test_as_slice() {
+    let v = Vector4::new(1.0f32, 2.0, 3.0, 4.0);
+    assert_eq!(v.as_slice().len(), 4);
+    assert_eq!(v.as_slice(), [1.0f32, 2.0, 3.0, 4.0].as_slice());
+
+    let mut v = Vector3::new(1i32, 2, 3);
+    v.as_mut_slice()[1] = 5;
+    assert_eq!(v, Vector3::new(1i32, 5, 3));
+    assert_eq!(Vector2::new(1i32, 2).as_slice().len(), 2);
+}
+
+#[test]
+fn test_angle_robust() {
+    use std::f64::consts::{PI, FRAC_PI_2};
+
+    // Perpendicular
+    assert!(Vector3::new(2.0f64, 0.0, 0.0).angle(&Vector3::new(0.0f64, 0.0, 3.0)).approx_eq(&rad(FRAC_PI_2)));
+    assert!(Vector4::new(0.0f64, 2.0, 0.0, 0.0).angle(&Vector4::new(0.0f64, 0.0, 0.0, 3.0)).approx_eq(&rad(FRAC_PI_2)));
+
+    // Parallel, including a tiny angle that acos would lose.
+    assert_eq!(Vector3::new(1.0f64, 2.0, 3.0).angle(&Vector3::new(2.0f64, 4.0, 6.0)), rad(0.0));
+    assert_eq!(Vector4::new(1.0f64, 2.0, 3.0, 4.0).angle(&Vector4::new(2.0f64, 4.0, 6.0, 8.0)), rad(0.0));
+    assert!(Vector3::new(1.0f64, 0.0, 0.0).angle(&Vector3::new(1.0f64, 1e-10, 0.0)).approx_eq_eps(&rad(1e-10), &1e-20));
+    assert!(Vector4::new(1.0f64, 0.0, 0.0, 0.0).angle(&Vector4::new(1.0f64, 0.0, 1e-10, 0.0)).approx_eq_eps(&rad(1e-10), &1e-20));
+
+    // Antiparallel
+    assert!(Vector2::new(1.0f64, 0.0).angle(&Vector2::new(-3.0f64, 0.0)).approx_eq(&rad(PI)));
+    assert!(Vector3::new(1.0f64, 2.0, 3.0).angle(&Vector3::new(-1.0f64, -2.0, -3.0)).approx_eq(&rad(PI)));
+    assert!(Vector4::new(1.0f64, 2.0, 3.0, 4.0).angle(&Vector4::new(-1.0f64, -2.0, -3.0, -4.0)).approx_eq(&rad(PI)));
+
+    // Zero-length inputs give a zero angle.
+    assert_eq!(Vector2::new(0.0f64, 0.0).angle(&Vector2::new(1.0f64, 0.0)), rad(0.0));
+    assert_eq!(Vector3::new(0.0f64, 0.0, 0.0).angle(&Vector3::new(1.0f64, 0.0, 0.0)), rad(0.0));
+    assert_eq!(Vector4::new(1.0f64, 0.0, 0.0, 0.0).angle(&Vector4::new(0.0f64, 0.0, 0.0, 0.0)), rad(0.0));
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    let normals = [Vector3::new(0.0f64, 0.0, 1.0),
+                   Vector3::new(0.0f64, 0.0, -1.0),
+                   Vector3::new(1.0f64, 0.0, 0.0),
+                   Vector3::new(0.0f64, -3.0, 0.0),
+                   Vector3::new(1.0f64, 2.0, 3.0),
+                   Vector3::new(1e-4f64, -1e-4, -1.0),
+                   Vector3::new(-0.5f64, 0.25, 2.0)];
+
+    for v in normals.iter() {
+        let n = v.normalize();
+        let (t, b) = v.orthonormal_basis();
+
+        assert!(t.length().approx_eq(&1.0));
+        assert!(b.length().approx_eq(&1.0));
+        assert!(t.dot(&b).approx_eq(&0.0));
+        assert!(t.dot(&n).approx_eq(&0.0));
+        assert!(b.dot(&n).approx_eq(&0.0));
+
+        // (t, b, n) is right-handed.
+        assert!(t.cross(&b).approx_eq(&n));
+    }
+}
+
+#[test]
+fn test_integer_vector_ops() {
+    let a = Vector2::new(3i32, -4);
+    let b = Vector2::new(-2i32, 5);
+
+    assert_eq!(a.add_v(&b), Vector2::new(1i32, 1));
+    assert_eq!(a.sub_v(&b), Vector2::new(5i32, -9));
+    assert_eq!(a.mul_s(2), Vector2::new(6i32, -8));
+    assert_eq!(a.dot(&b), -26i32);
+    assert_eq!(dot(a, b), -26i32);
+    assert_eq!(a.min(&b), Vector2::new(-2i32, -4));
+    assert_eq!(a.max(&b), Vector2::new(3i32, 5));
+    assert_eq!(a.comp_min(), -4i32);
+    assert_eq!(a.comp_max(), 3i32);
+    assert_eq!(a.l1_norm(), 7i32);
+    assert_eq!(a.perp_dot(&b), 7i32);
+}
+
+#[test]
+fn test_perp_dot() {
+    let x = Vector2::new(2.0f64, 0.0);
+    let ccw = Vector2::new(1.0f64, 3.0);
+    let cw = Vector2::new(1.0f64, -3.0);
+
+    assert_eq!(x.perp_dot(&ccw), 6.0);
+    assert_eq!(x.perp_dot(&cw), -6.0);
+    assert_eq!(ccw.perp_dot(&x), -x.perp_dot(&ccw));
+    assert_eq!(x.perp_dot(&x.mul_s(-4.0)), 0.0);
+}
+
+#[test]
+fn test_hash_map_key() {
+    use std::collections::HashMap;
+
+    let mut tiles = HashMap::new();
+    tiles.insert(Vector2::new(1i32, 2), "grass");
+    tiles.insert(Vector2::new(-3i32, 0), "water");
+    tiles.insert(Vector2::new(1i32, 2), "sand");
+
+    assert_eq!(tiles.len(), 2);
+    assert_eq!(tiles.get(&Vector2::new(1i32, 2)), Some(&"sand"));
+    assert_eq!(tiles.get(&Vector2::new(-3i32, 0)), Some(&"water"));
+    assert_eq!(tiles.get(&Vector2::new(2i32, 1)), None);
+}
+
+#[test]
+fn test_lexicographic_order() {
+    let mut vs = vec![Vector3::new(1i32, 2, 3), Vector3::new(1i32, 1, 9), Vector3::new(0i32, 5, 5)];
+    vs.sort();
+    assert_eq!(vs, vec![Vector3::new(0i32, 5, 5), Vector3::new(1i32, 1, 9), Vector3::new(1i32, 2, 3)]);
+
+    assert!(Vector2::new(1.0f64, 2.0) < Vector2::new(1.0f64, 3.0));
+}
+
+#[test]
+fn test_swizzle() {
+    assert_eq!(Vector2::new(1i32, 2).yx(), Vector2::new(2i32, 1));
+
+    let v = Vector3::new(1i32, 2, 3);
+    assert_eq!(v.xy(), Vector2::new(1i32, 2));
+    assert_eq!(v.xz(), Vector2::new(1i32, 3));
+    assert_eq!(v.yz(), Vector2::new(2i32, 3));
+
+    let v = Vector4::new(1i32, 2, 3, 4);
+    assert_eq!(v.xy(), Vector2::new(1i32, 2));
+    assert_eq!(v.xyz(), Vector3::new(1i32, 2, 3));
+    assert_eq!(v.xyw(), Vector3::new(1i32, 2, 4));
+}
+
+#[test]
+fn test_extend_truncate() {
+    let v2 = Vector2::new(1i32, 2);
+    let v3 = Vector3::new(1i32, 2, 3);
+    let v4 = Vector4::new(1i32, 2, 3, 4);
+
+    assert_eq!(v2.extend(3), v3);
+    assert_eq!(v3.extend(4), v4);
+    assert_eq!(v3.truncate(), v2);
+    assert_eq!(v4.truncate(), v3);
+    assert_eq!(v2.extend(7).truncate(), v2);
+    assert_eq!(v3.extend(7).truncate(), v3);
+
+    assert_eq!(v3.truncate_n(0), Vector2::new(2i32, 3));
+    assert_eq!(v3.truncate_n(1), Vector2::new(1i32, 3));
+    assert_eq!(v3.truncate_n(2), Vector2::new(1i32, 2));
+
+    assert_eq!(v4.truncate_n(0), Vector3::new(2i32, 3, 4));
+    assert_eq!(v4.truncate_n(1), Vector3::new(1i32, 3, 4));
+    assert_eq!(v4.truncate_n(2), Vector3::new(1i32, 2, 4));
+    assert_eq!(v4.truncate_n(3), Vector3::new(1i32, 2, 3));
+}
+
+#[test]
+#[should_panic]
+fn test_truncate_n_out_of_range() {
+    Vector3::new(1i32, 2, 3).truncate_n(3);
+}
+
+#[test]
+fn test_homogenize() {
+    assert_eq!(Vector4::new(2.0f64, 4.0, -6.0, 2.0).homogenize(), Some(Vector3::new(1.0f64, 2.0, -3.0)));
+    assert_eq!(Vector4::new(1.0f64, 2.0, 3.0, 0.0).homogenize(), None);
+
+    let proj = perspective(deg(90.0f64), 1.0, 1.0, 10.0);
+    let near = proj.mul_v(&Vector4::new(1.0f64, -0.5, -1.0, 1.0)).homogenize().unwrap();
+    assert!(near.approx_eq(&Vector3::new(1.0f64, -0.5, -1.0)));
+    let far = proj.mul_v(&Vector4::new(5.0f64, 0.0, -10.0, 1.0)).homogenize().unwrap();
+    assert!(far.approx_eq(&Vector3::new(0.5f64, 0.0, 1.0)));
+}
+
+#[test]
+fn test_is_finite_is_nan() {
+    let v = Vector3::new(1.0f64, -2.0, 3.0);
+    assert!(v.is_finite());
+    assert!(!v.is_nan());
+
+    let nan = Vector3::new(1.0f64, f64::NAN, 3.0);
+    assert!(!nan.is_finite());
+    assert!(nan.is_nan());
+
+    let inf = Vector4::new(1.0f64, 2.0, 3.0, f64::NEG_INFINITY);
+    assert!(!inf.is_finite());
+    assert!(!inf.is_nan());
+}
+
+#[test]
+fn test_rounding() {
+    let v = Vector3::new(-1.7f64, 2.3, -0.5);
+    assert_eq!(v.abs(), Vector3::new(1.7f64, 2.3, 0.5));
+    assert_eq!(v.floor(), Vector3::new(-2.0f64, 2.0, -1.0));
+    assert_eq!(v.ceil(), Vector3::new(-1.0f64, 3.0, -0.0));
+    assert_eq!(v.round(), Vector3::new(-2.0f64, 2.0, -1.0));
+    assert!(v.fract().approx_eq(&Vector3::new(-0.7f64, 0.3, -0.5)));
+
+    assert_eq!(Vector2::new(0.5f32, 1.5).round(), Vector2::new(1.0f32, 2.0));
+    assert_eq!(Vector4::new(-0.25f32, 3.75, 1.0, -8.0).floor(), Vector4::new(-1.0f32, 3.0, 1.0, -8.0));
+}
+
+#[test]
+fn test_signum_copysign() {
+    let v = Vector4::new(-2.5f64, 3.0, 0.0, -0.0);
+    assert_eq!(v.signum(), Vector4::new(-1.0f64, 1.0, 1.0, -1.0));
+    assert!(Vector2::new(f64::NAN, 1.0).signum().x.is_nan());
+
+    let magnitudes = Vector3::new(1.0f64, -2.0, 3.0);
+    assert_eq!(magnitudes.copysign(&Vector3::new(-5.0f64, 0.5, -0.0)), Vector3::new(-1.0f64, 2.0, -3.0));
+    assert_eq!(magnitudes.copysign(&magnitudes), magnitudes);
+}
+
+#[test]
+fn test_in_place_ops() {
+    let a = Vector3::new(1.0f64, -2.0, 4.0);
+    let b = Vector3::new(0.5f64, 1.0, -3.0);
+
+    let mut v = a; v.add_self_v(&b); assert_eq!(v, a.add_v(&b));
+    let mut v = a; v.sub_self_v(&b); assert_eq!(v, a.sub_v(&b));
+    let mut v = a; v.mul_self_v(&b); assert_eq!(v, a.mul_v(&b));
+    let mut v = a; v.div_self_v(&b); assert_eq!(v, a.div_v(&b));
+    let mut v = a; v.add_self_s(2.0); assert_eq!(v, a.add_s(2.0));
+    let mut v = a; v.sub_self_s(2.0); assert_eq!(v, a.sub_s(2.0));
+    let mut v = a; v.mul_self_s(2.0); assert_eq!(v, a.mul_s(2.0));
+    let mut v = a; v.div_self_s(2.0); assert_eq!(v, a.div_s(2.0));
+    let mut v = a; v.neg_self(); assert_eq!(v, -a);
+    let mut v = a; v.normalize_self(); assert_eq!(v, a.normalize());
+    let mut v = a; v.lerp_self(&b, 0.25); assert_eq!(v, a.lerp(&b, 0.25));
+}
+
+#[test]
+fn test_operators() {
+    let a = Vector3::new(1.0f64, -2.0, 4.0);
+    let b = Vector3::new(0.5f64, 1.0, -3.0);
+
+    assert_eq!(a + b, a.add_v(&b));
+    assert_eq!(a - b, a.sub_v(&b));
+    assert_eq!(-a, Vector3::new(-1.0f64, 2.0, -4.0));
+    assert_eq!(a * 2.0, a.mul_s(2.0));
+    assert_eq!(a / 2.0, a.div_s(2.0));
+    assert_eq!(Vector4::new(1i32, 2, 3, 4) * 3, Vector4::new(3i32, 6, 9, 12));
+    assert_eq!(a[1], -2.0);
+}
+
+#[test]
+fn test_accumulate_in_place() {
+    let velocities = [Vector3::new(1.0f64, 0.0, 2.0), Vector3::new(-0.5f64, 3.0, 1.0), Vector3::new(2.0f64, -1.0, 0.5)];
+    let dt = 0.5;
+
+    let mut pos = Vector3::new(0.0f64, 1.0, 0.0);
+    for v in velocities.iter() {
+        pos.add_self_v(&v.mul_s(dt));
+    }
+
+    let folded = velocities.iter().fold(Vector3::new(0.0f64, 1.0, 0.0), |p, &v| p + v * dt);
+    assert_eq!(pos, folded);
+}
+
+#[test]
+fn test_default() {
+    use std::default::Default;
+
+    let v3: Vector3<f64> = Default::default();
+    let v4: Vector4<i32> = Default::default();
+    assert_eq!(v3, Vector3::new(0.0f64, 0.0, 0.0));
+    assert_eq!(v4, zero());
+}
+
+#[test]
+fn test_sum_product() {
+    let forces = vec![Vector3::new(1.0f64, 0.0, 2.0), Vector3::new(-0.5f64, 3.0, 1.0), Vector3::new(2.0f64, -1.0, 0.5)];
+    let folded = forces.iter().fold(Vector3::new(0.0f64, 0.0, 0.0), |acc, &v| acc + v);
+    assert_eq!(sum(forces.into_iter()), folded);
+
+    let empty: Vec<Vector2<f32>> = vec![];
+    assert_eq!(sum(empty.into_iter()), Vector2::new(0.0f32, 0.0));
+    assert_eq!(product(vec![Vector2::new(2i32, 3), Vector2::new(4i32, -1)].into_iter()), Vector2::new(8i32, -3));
+    assert_eq!(product(Vec::<Vector2<i32>>::new().into_iter()), Vector2::new(1i32, 1));
+}
+
+#[test]
+fn test_from_iter() {
+    let v: Vector3<f64> = [1.0f64, 2.0, 3.0].iter().cloned().collect();
+    assert_eq!(v, Vector3::new(1.0f64, 2.0, 3.0));
+
+    // Only as many items as there are components are consumed
+    let mut items = 1..10i32;
+    let v: Vector4<i32> = items.by_ref().collect();
+    assert_eq!(v, Vector4::new(1i32, 2, 3, 4));
+    assert_eq!(items.next(), Some(5));
+}
+
+#[test]
+#[should_panic]
+fn test_from_iter_too_few() {
+    let _: Vector3<f64> = [1.0f64, 2.0].iter().cloned().collect();
+}
+
+#[test]
+fn test_iter() {
+    let mut v = Vector4::new(1i32, 2, 3, 4);
+    assert_eq!(v.iter().cloned().collect::<Vec<i32>>(), vec![1i32, 2, 3, 4]);
+    assert_eq!(v.iter().count(), 4);
+
+    for x in v.iter_mut() {
+        *x = *x * 10;
+    }
+    assert_eq!(v, Vector4::new(10i32, 20, 30, 40));
+    let doubled: Vector2<f64> = Vector2::new(1.5f64, -2.0).iter().map(|&x| x * 2.0).collect();
+    assert_eq!(doubled, Vector2::new(3.0f64, -4.0));
+}
+
+#[test]
+fn test_map_to_zip_map() {
+    let v = Vector3::new(1.0f64, -2.0, 3.0);
+    assert_eq!(v.map_to(|x| x * x), Vector3::new(1.0f64, 4.0, 9.0));
+    let positive = v.map_to(|x| x > 0.0);
+    assert!(positive.x && !positive.y && positive.z);
+    assert_eq!(v, Vector3::new(1.0f64, -2.0, 3.0));
+
+    let a = Vector4::new(1i32, 2, 3, 4);
+    let b = Vector4::new(10i32, 20, 30, 40);
+    assert_eq!(a.zip_map(&b, |x, y| x + y), a + b);
+    assert_eq!(Vector2::new(2.0f32, 3.0).zip_map(&Vector2::new(1i32, 2), |x, n| x.powi(n)), Vector2::new(2.0f32, 9.0));
 }